@@ -2,8 +2,11 @@ use crate::{
     io::{self, Read, Seek},
     BinRead, BinResult, Endian, Error, NamedArgs,
 };
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use alloc::{borrow::Cow, rc::Rc};
 #[cfg(not(feature = "std"))]
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{borrow::ToOwned, boxed::Box, vec::Vec};
 use core::num::{
     NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU128, NonZeroU16,
     NonZeroU32, NonZeroU64, NonZeroU8,
@@ -210,6 +213,47 @@ impl<T: BinRead> BinRead for Box<T> {
     }
 }
 
+impl<T: BinRead> BinRead for Rc<T> {
+    type Args<'a> = T::Args<'a>;
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<Self> {
+        Ok(Rc::new(T::read_options(reader, endian, args)?))
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T: BinRead> BinRead for Arc<T> {
+    type Args<'a> = T::Args<'a>;
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<Self> {
+        Ok(Arc::new(T::read_options(reader, endian, args)?))
+    }
+}
+
+impl<T> BinRead for Cow<'_, T>
+where
+    T: ToOwned + ?Sized,
+    T::Owned: BinRead,
+{
+    type Args<'a> = <T::Owned as BinRead>::Args<'a>;
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<Self> {
+        Ok(Cow::Owned(T::Owned::read_options(reader, endian, args)?))
+    }
+}
+
 impl<T: BinRead> BinRead for Option<T> {
     type Args<'a> = T::Args<'a>;
 
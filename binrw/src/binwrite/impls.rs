@@ -2,8 +2,11 @@ use crate::{
     io::{Seek, Write},
     BinResult, BinWrite, Endian,
 };
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use alloc::{borrow::Cow, rc::Rc};
 #[cfg(not(feature = "std"))]
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{borrow::ToOwned, boxed::Box, vec::Vec};
 use core::{
     any::Any,
     marker::PhantomData,
@@ -179,6 +182,46 @@ impl<T: BinWrite + ?Sized + 'static> BinWrite for Box<T> {
     }
 }
 
+impl<T: BinWrite + ?Sized> BinWrite for Rc<T> {
+    type Args<'a> = T::Args<'a>;
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<()> {
+        (**self).write_options(writer, endian, args)
+    }
+}
+
+#[cfg(target_has_atomic = "ptr")]
+impl<T: BinWrite + ?Sized> BinWrite for Arc<T> {
+    type Args<'a> = T::Args<'a>;
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<()> {
+        (**self).write_options(writer, endian, args)
+    }
+}
+
+impl<T: BinWrite + ToOwned + ?Sized> BinWrite for Cow<'_, T> {
+    type Args<'a> = T::Args<'a>;
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<()> {
+        (**self).write_options(writer, endian, args)
+    }
+}
+
 impl<T: BinWrite> BinWrite for Option<T> {
     type Args<'a> = T::Args<'a>;
 
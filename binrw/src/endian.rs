@@ -1,8 +1,12 @@
 //! Type definitions for byte order handling.
 
-use crate::BinResult;
+use crate::{
+    io::{Read, Seek, Write},
+    BinRead, BinResult, BinWrite,
+};
 #[cfg(not(feature = "std"))]
 use alloc::boxed::Box;
+use core::mem::size_of;
 pub use Endian::{Big as BE, Little as LE};
 
 /// Defines the order of bytes in a multi-byte type.
@@ -61,5 +65,128 @@ impl core::fmt::Display for Endian {
     }
 }
 
+/// A wrapper for numbers which are stored as a sequence of `WORD_SIZE`-byte
+/// words in the opposite order to the bytes within each word.
+///
+/// The byte order given when reading or writing a `WordSwapped` value is the
+/// byte order within each word. This describes layouts like:
+///
+/// * PDP-endian (“middle-endian”) 32-bit values, which are two little-endian
+///   16-bit words with the most significant word first:
+///   `WordSwapped<u32, 2>` with little-endian byte order.
+/// * 64-bit values stored as two little-endian 32-bit words with the most
+///   significant word first: `WordSwapped<u64, 4>` with little-endian byte
+///   order.
+///
+/// `WordSwapped` is implemented for all the primitive integer and floating
+/// point types. `WORD_SIZE` must be non-zero and evenly divide the size of the
+/// wrapped type, or the program will fail to compile.
+///
+/// # Examples
+///
+/// ```
+/// use binrw::{BinRead, endian::WordSwapped, io::Cursor};
+///
+/// #[derive(BinRead)]
+/// #[br(little)]
+/// struct Pdp {
+///     value: WordSwapped<u32, 2>,
+/// }
+///
+/// let pdp = Pdp::read(&mut Cursor::new(b"\x0B\x0A\x0D\x0C")).unwrap();
+/// assert_eq!(*pdp.value, 0x0A0B_0C0D);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct WordSwapped<T, const WORD_SIZE: usize>(pub T);
+
+impl<T, const WORD_SIZE: usize> WordSwapped<T, WORD_SIZE> {
+    const VALID_WORD_SIZE: () = assert!(
+        WORD_SIZE != 0 && size_of::<T>() % WORD_SIZE == 0,
+        "word size must be non-zero and evenly divide the size of the value"
+    );
+
+    /// Consumes this wrapper, returning the wrapped value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T, const WORD_SIZE: usize> From<T> for WordSwapped<T, WORD_SIZE> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
+
+impl<T, const WORD_SIZE: usize> core::ops::Deref for WordSwapped<T, WORD_SIZE> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.0
+    }
+}
+
+impl<T, const WORD_SIZE: usize> core::ops::DerefMut for WordSwapped<T, WORD_SIZE> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.0
+    }
+}
+
+/// Reverses the order of the `word_size`-byte words in `bytes` without
+/// changing the order of the bytes within each word.
+fn swap_words(bytes: &mut [u8], word_size: usize) {
+    bytes.reverse();
+    for word in bytes.chunks_exact_mut(word_size) {
+        word.reverse();
+    }
+}
+
+macro_rules! word_swapped_impl {
+    ($($type_name:ty),*$(,)?) => {
+        $(
+            impl<const WORD_SIZE: usize> BinRead for WordSwapped<$type_name, WORD_SIZE> {
+                type Args<'a> = ();
+
+                fn read_options<R: Read + Seek>(
+                    reader: &mut R,
+                    endian: Endian,
+                    (): Self::Args<'_>,
+                ) -> BinResult<Self> {
+                    let () = Self::VALID_WORD_SIZE;
+                    let mut val = [0; size_of::<$type_name>()];
+                    let pos = reader.stream_position()?;
+
+                    reader.read_exact(&mut val).or_else(crate::__private::restore_position(reader, pos))?;
+                    swap_words(&mut val, WORD_SIZE);
+                    Ok(Self(match endian {
+                        Endian::Big => <$type_name>::from_be_bytes(val),
+                        Endian::Little => <$type_name>::from_le_bytes(val),
+                    }))
+                }
+            }
+
+            impl<const WORD_SIZE: usize> BinWrite for WordSwapped<$type_name, WORD_SIZE> {
+                type Args<'a> = ();
+
+                fn write_options<W: Write + Seek>(
+                    &self,
+                    writer: &mut W,
+                    endian: Endian,
+                    (): Self::Args<'_>,
+                ) -> BinResult<()> {
+                    let () = Self::VALID_WORD_SIZE;
+                    let mut val = match endian {
+                        Endian::Big => self.0.to_be_bytes(),
+                        Endian::Little => self.0.to_le_bytes(),
+                    };
+                    swap_words(&mut val, WORD_SIZE);
+                    writer.write_all(&val).map_err(Into::into)
+                }
+            }
+        )*
+    };
+}
+
+word_swapped_impl!(u16, u32, u64, u128, i16, i32, i64, i128, f32, f64);
+
 const BOM: u16 = 0xFEFF;
 const REVERSE_BOM: u16 = 0xFFFE;
@@ -1,11 +1,11 @@
 //! Helper functions for reading and writing data.
 
 use crate::{
-    io::{self, Read, Seek},
-    BinRead, BinResult, Endian, Error,
+    io::{self, BitOrder, BitReader, BitWriter, Read, Seek, SeekFrom, Write},
+    BinRead, BinResult, BinWrite, Endian, Error,
 };
 #[cfg(not(feature = "std"))]
-use alloc::vec::Vec;
+use alloc::{format, vec::Vec};
 use core::iter::from_fn;
 
 /// Creates a parser that reads items into a collection until a condition is
@@ -100,6 +100,97 @@ where
     }
 }
 
+/// Creates a writer that writes the items of a collection which ends with an
+/// item that meets a condition. This is the counterpart of [`until`].
+///
+/// This helper can be used to write any collection type which can be iterated
+/// by reference.
+///
+/// # Errors
+///
+/// If the last item does not meet the condition, or if any other item does,
+/// an [`AssertFail`](Error::AssertFail) error is returned, since the written
+/// data would not be read back the same way.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{binrw, helpers::{until, write_until}, io::Cursor, BinWrite};
+/// #[binrw]
+/// struct NullTerminated {
+///     #[br(parse_with = until(|&byte| byte == 0))]
+///     #[bw(write_with = write_until(|&byte| byte == 0))]
+///     data: Vec<u8>,
+/// }
+///
+/// # let mut x = Cursor::new(Vec::new());
+/// # NullTerminated { data: vec![1, 2, 3, 4, 0] }.write_be(&mut x).unwrap();
+/// # assert_eq!(x.into_inner(), b"\x01\x02\x03\x04\0");
+/// ```
+pub fn write_until<Writer, T, V, CondFn, Arg>(
+    cond: CondFn,
+) -> impl Fn(&V, &mut Writer, Endian, Arg) -> BinResult<()>
+where
+    T: for<'a> BinWrite<Args<'a> = Arg>,
+    V: ?Sized,
+    for<'a> &'a V: IntoIterator<Item = &'a T>,
+    Writer: Write + Seek,
+    CondFn: Fn(&T) -> bool,
+    Arg: Clone,
+{
+    write_until_with(cond, T::write_options)
+}
+
+/// Creates a writer that uses a given function to write the items of a
+/// collection which ends with an item that meets a condition. This is the
+/// counterpart of [`until_with`].
+///
+/// The given `write` function is called once for each item.
+///
+/// This helper can be used to write any collection type which can be iterated
+/// by reference.
+///
+/// # Errors
+///
+/// If the last item does not meet the condition, or if any other item does,
+/// an [`AssertFail`](Error::AssertFail) error is returned, since the written
+/// data would not be read back the same way.
+pub fn write_until_with<Writer, T, V, CondFn, Arg, WriteFn>(
+    cond: CondFn,
+    write: WriteFn,
+) -> impl Fn(&V, &mut Writer, Endian, Arg) -> BinResult<()>
+where
+    V: ?Sized,
+    for<'a> &'a V: IntoIterator<Item = &'a T>,
+    Writer: Write + Seek,
+    CondFn: Fn(&T) -> bool,
+    Arg: Clone,
+    WriteFn: Fn(&T, &mut Writer, Endian, Arg) -> BinResult<()>,
+{
+    move |values, writer, endian, args| {
+        let mut terminated = false;
+        for value in values {
+            if terminated {
+                return Err(Error::AssertFail {
+                    pos: writer.stream_position()?,
+                    message: "found an item after the terminal item".into(),
+                });
+            }
+            terminated = cond(value);
+            write(value, writer, endian, args.clone())?;
+        }
+
+        if terminated {
+            Ok(())
+        } else {
+            Err(Error::AssertFail {
+                pos: writer.stream_position()?,
+                message: "the last item does not meet the terminal condition".into(),
+            })
+        }
+    }
+}
+
 /// Creates a parser that reads items into a collection until a condition is
 /// met. The terminal item is discarded.
 ///
@@ -186,6 +277,382 @@ where
     }
 }
 
+/// Creates a writer that writes the items of a collection followed by a
+/// terminal item which meets a condition. This is the counterpart of
+/// [`until_exclusive`].
+///
+/// This helper can be used to write any collection type which can be iterated
+/// by reference.
+///
+/// # Errors
+///
+/// If any item of the collection meets the condition, or if the terminal item
+/// does not, an [`AssertFail`](Error::AssertFail) error is returned, since the
+/// written data would not be read back the same way.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{binrw, helpers::{until_exclusive, write_until_exclusive}, io::Cursor, BinWrite};
+/// #[binrw]
+/// struct NullTerminated {
+///     #[br(parse_with = until_exclusive(|&byte| byte == 0))]
+///     #[bw(write_with = write_until_exclusive(|&byte| byte == 0, 0))]
+///     data: Vec<u8>,
+/// }
+///
+/// # let mut x = Cursor::new(Vec::new());
+/// # NullTerminated { data: vec![1, 2, 3, 4] }.write_be(&mut x).unwrap();
+/// # assert_eq!(x.into_inner(), b"\x01\x02\x03\x04\0");
+/// ```
+pub fn write_until_exclusive<Writer, T, V, CondFn, Arg>(
+    cond: CondFn,
+    terminator: T,
+) -> impl Fn(&V, &mut Writer, Endian, Arg) -> BinResult<()>
+where
+    T: for<'a> BinWrite<Args<'a> = Arg>,
+    V: ?Sized,
+    for<'a> &'a V: IntoIterator<Item = &'a T>,
+    Writer: Write + Seek,
+    CondFn: Fn(&T) -> bool,
+    Arg: Clone,
+{
+    write_until_exclusive_with(cond, terminator, T::write_options)
+}
+
+/// Creates a writer that uses a given function to write the items of a
+/// collection followed by a terminal item which meets a condition. This is the
+/// counterpart of [`until_exclusive_with`].
+///
+/// The given `write` function is called once for each item, and once for the
+/// terminal item.
+///
+/// This helper can be used to write any collection type which can be iterated
+/// by reference.
+///
+/// # Errors
+///
+/// If any item of the collection meets the condition, or if the terminal item
+/// does not, an [`AssertFail`](Error::AssertFail) error is returned, since the
+/// written data would not be read back the same way.
+pub fn write_until_exclusive_with<Writer, T, V, CondFn, Arg, WriteFn>(
+    cond: CondFn,
+    terminator: T,
+    write: WriteFn,
+) -> impl Fn(&V, &mut Writer, Endian, Arg) -> BinResult<()>
+where
+    V: ?Sized,
+    for<'a> &'a V: IntoIterator<Item = &'a T>,
+    Writer: Write + Seek,
+    CondFn: Fn(&T) -> bool,
+    Arg: Clone,
+    WriteFn: Fn(&T, &mut Writer, Endian, Arg) -> BinResult<()>,
+{
+    move |values, writer, endian, args| {
+        if !cond(&terminator) {
+            return Err(Error::AssertFail {
+                pos: writer.stream_position()?,
+                message: "the terminal item does not meet the terminal condition".into(),
+            });
+        }
+
+        for value in values {
+            if cond(value) {
+                return Err(Error::AssertFail {
+                    pos: writer.stream_position()?,
+                    message: "found an item which meets the terminal condition".into(),
+                });
+            }
+            write(value, writer, endian, args.clone())?;
+        }
+        write(&terminator, writer, endian, args)
+    }
+}
+
+/// Creates a parser that reads items into a collection until the most
+/// recently read items are equal to a terminator sequence. The terminator items
+/// are added to the collection.
+///
+/// This helper can be used to read into any collection type that implements
+/// [`FromIterator`].
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{BinRead, helpers::until_seq, io::Cursor, BinReaderExt};
+/// #[derive(BinRead)]
+/// struct Terminated {
+///     #[br(parse_with = until_seq([0xffff, 0xffff]))]
+///     data: Vec<u16>,
+/// }
+///
+/// # let mut x = Cursor::new(b"\0\x01\xff\xff\0\x02\xff\xff\xff\xff");
+/// # let x: Terminated = x.read_be().unwrap();
+/// # assert_eq!(x.data, &[1, 0xffff, 2, 0xffff, 0xffff]);
+/// ```
+pub fn until_seq<Reader, T, Term, Arg, Ret>(
+    terminator: Term,
+) -> impl Fn(&mut Reader, Endian, Arg) -> BinResult<Ret>
+where
+    T: for<'a> BinRead<Args<'a> = Arg> + PartialEq,
+    Reader: Read + Seek,
+    Term: AsRef<[T]>,
+    Arg: Clone,
+    Ret: FromIterator<T>,
+{
+    until_seq_with(terminator, T::read_options)
+}
+
+/// Creates a parser that uses a given function to read items into a collection
+/// until the most recently read items are equal to a terminator sequence. The
+/// terminator items are added to the collection.
+///
+/// The given `read` function should return one item each time it is called.
+///
+/// This helper can be used to read into any collection type that implements
+/// [`FromIterator`].
+pub fn until_seq_with<Reader, T, Term, Arg, ReadFn, Ret>(
+    terminator: Term,
+    read: ReadFn,
+) -> impl Fn(&mut Reader, Endian, Arg) -> BinResult<Ret>
+where
+    T: PartialEq,
+    Reader: Read + Seek,
+    Term: AsRef<[T]>,
+    Arg: Clone,
+    ReadFn: Fn(&mut Reader, Endian, Arg) -> BinResult<T>,
+    Ret: FromIterator<T>,
+{
+    move |reader, endian, args| {
+        read_until_seq(terminator.as_ref(), || read(reader, endian, args.clone()))
+            .map(|items| items.into_iter().collect())
+    }
+}
+
+/// Creates a parser that reads items into a collection until the most
+/// recently read items are equal to a terminator sequence. The terminator items
+/// are discarded.
+///
+/// This helper can be used to read into any collection type that implements
+/// [`FromIterator`].
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{BinRead, helpers::until_seq_exclusive, io::Cursor, BinReaderExt};
+/// #[derive(BinRead)]
+/// struct Record {
+///     id: u8,
+///     value: u8,
+/// }
+///
+/// #[derive(BinRead)]
+/// struct Records {
+///     #[br(parse_with = until_seq_exclusive([Record { id: 0, value: 0 }]))]
+///     records: Vec<Record>,
+/// }
+/// # impl PartialEq for Record {
+/// #     fn eq(&self, other: &Self) -> bool { (self.id, self.value) == (other.id, other.value) }
+/// # }
+///
+/// # let mut x = Cursor::new(b"\x01\x02\x03\x00\x00\x00");
+/// # let x: Records = x.read_be().unwrap();
+/// # assert_eq!(x.records.len(), 2);
+/// ```
+pub fn until_seq_exclusive<Reader, T, Term, Arg, Ret>(
+    terminator: Term,
+) -> impl Fn(&mut Reader, Endian, Arg) -> BinResult<Ret>
+where
+    T: for<'a> BinRead<Args<'a> = Arg> + PartialEq,
+    Reader: Read + Seek,
+    Term: AsRef<[T]>,
+    Arg: Clone,
+    Ret: FromIterator<T>,
+{
+    until_seq_exclusive_with(terminator, T::read_options)
+}
+
+/// Creates a parser that uses a given function to read items into a collection
+/// until the most recently read items are equal to a terminator sequence. The
+/// terminator items are discarded.
+///
+/// The given `read` function should return one item each time it is called.
+///
+/// This helper can be used to read into any collection type that implements
+/// [`FromIterator`].
+pub fn until_seq_exclusive_with<Reader, T, Term, Arg, ReadFn, Ret>(
+    terminator: Term,
+    read: ReadFn,
+) -> impl Fn(&mut Reader, Endian, Arg) -> BinResult<Ret>
+where
+    T: PartialEq,
+    Reader: Read + Seek,
+    Term: AsRef<[T]>,
+    Arg: Clone,
+    ReadFn: Fn(&mut Reader, Endian, Arg) -> BinResult<T>,
+    Ret: FromIterator<T>,
+{
+    move |reader, endian, args| {
+        let terminator = terminator.as_ref();
+        read_until_seq(terminator, || read(reader, endian, args.clone())).map(|mut items| {
+            items.truncate(items.len() - terminator.len());
+            items.into_iter().collect()
+        })
+    }
+}
+
+fn read_until_seq<T: PartialEq>(
+    terminator: &[T],
+    mut read: impl FnMut() -> BinResult<T>,
+) -> BinResult<Vec<T>> {
+    let mut items = Vec::new();
+    while !items.ends_with(terminator) {
+        items.push(read()?);
+    }
+    Ok(items)
+}
+
+/// Creates a parser that reads bytes until a terminator byte sequence is
+/// found. The terminator is added to the collection.
+///
+/// The input is scanned in blocks, and the stream is seeked back to the end of
+/// the terminator once it is found, so this is much faster than reading one
+/// byte at a time. Use a buffered reader like [`BufReader`](crate::io::BufReader)
+/// to make the backwards seek cheap.
+///
+/// This helper can be used to read into any collection type that implements
+/// [`FromIterator`].
+///
+/// # Errors
+///
+/// If the end of the input is reached before the terminator is found, or if
+/// reading fails, an [`Error`] variant will be returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{BinRead, helpers::until_bytes, io::Cursor, BinReaderExt};
+/// #[derive(BinRead)]
+/// struct Line {
+///     #[br(parse_with = until_bytes(b"\r\n"))]
+///     data: Vec<u8>,
+/// }
+///
+/// # let mut x = Cursor::new(b"a\rb\r\nc");
+/// # let x: Line = x.read_be().unwrap();
+/// # assert_eq!(x.data, b"a\rb\r\n");
+/// ```
+pub fn until_bytes<Reader, Term, Ret>(
+    terminator: Term,
+) -> impl Fn(&mut Reader, Endian, ()) -> BinResult<Ret>
+where
+    Reader: Read + Seek,
+    Term: AsRef<[u8]>,
+    Ret: FromIterator<u8>,
+{
+    move |reader, _, ()| {
+        read_until_bytes(reader, terminator.as_ref()).map(|data| data.into_iter().collect())
+    }
+}
+
+/// Creates a parser that reads bytes until a terminator byte sequence is
+/// found. The terminator is consumed, but is not added to the collection.
+///
+/// The input is scanned in blocks, and the stream is seeked back to the end of
+/// the terminator once it is found, so this is much faster than reading one
+/// byte at a time. Use a buffered reader like [`BufReader`](crate::io::BufReader)
+/// to make the backwards seek cheap.
+///
+/// This helper can be used to read into any collection type that implements
+/// [`FromIterator`].
+///
+/// # Errors
+///
+/// If the end of the input is reached before the terminator is found, or if
+/// reading fails, an [`Error`] variant will be returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{BinRead, helpers::until_bytes_exclusive, io::Cursor, BinReaderExt};
+/// #[derive(BinRead)]
+/// struct Lines {
+///     #[br(parse_with = until_bytes_exclusive(b"\r\n"))]
+///     first: Vec<u8>,
+///     #[br(parse_with = until_bytes_exclusive(b"\r\n"))]
+///     second: Vec<u8>,
+/// }
+///
+/// # let mut x = Cursor::new(b"abc\r\ndef\r\n");
+/// # let x: Lines = x.read_be().unwrap();
+/// # assert_eq!(x.first, b"abc");
+/// # assert_eq!(x.second, b"def");
+/// ```
+pub fn until_bytes_exclusive<Reader, Term, Ret>(
+    terminator: Term,
+) -> impl Fn(&mut Reader, Endian, ()) -> BinResult<Ret>
+where
+    Reader: Read + Seek,
+    Term: AsRef<[u8]>,
+    Ret: FromIterator<u8>,
+{
+    move |reader, _, ()| {
+        let terminator = terminator.as_ref();
+        read_until_bytes(reader, terminator).map(|mut data| {
+            data.truncate(data.len() - terminator.len());
+            data.into_iter().collect()
+        })
+    }
+}
+
+fn read_until_bytes<R: Read + Seek>(reader: &mut R, terminator: &[u8]) -> BinResult<Vec<u8>> {
+    const BLOCK_SIZE: usize = 256;
+
+    let mut data = Vec::new();
+    if terminator.is_empty() {
+        return Ok(data);
+    }
+
+    // The position in `data` from which a terminator could start which was
+    // not already checked
+    let mut search_from = 0;
+    loop {
+        let start = data.len();
+        data.resize(start + BLOCK_SIZE, 0);
+        let n = loop {
+            match reader.read(&mut data[start..]) {
+                Ok(n) => break n,
+                Err(err) if err.kind() == io::ErrorKind::Interrupted => {}
+                Err(err) => return Err(err.into()),
+            }
+        };
+        data.truncate(start + n);
+
+        if let Some(index) = data[search_from..]
+            .windows(terminator.len())
+            .position(|window| window == terminator)
+        {
+            let end = search_from + index + terminator.len();
+            let overread = data.len() - end;
+            if overread != 0 {
+                // Lint: `overread` is at most `BLOCK_SIZE`
+                #[allow(clippy::cast_possible_wrap)]
+                reader.seek(SeekFrom::Current(-(overread as i64)))?;
+            }
+            data.truncate(end);
+            return Ok(data);
+        } else if n == 0 && start == data.len() {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "reached end of input before finding terminator",
+            )
+            .into());
+        }
+
+        search_from = data.len().saturating_sub(terminator.len() - 1);
+    }
+}
+
 /// Creates a parser that reads items into a collection until the end of the
 /// input stream.
 ///
@@ -276,6 +743,73 @@ where
     }
 }
 
+/// Writes every item of a collection, with nothing after the last item. This is
+/// the counterpart of [`until_eof`].
+///
+/// This helper can be used to write any collection type which can be iterated
+/// by reference.
+///
+/// # Errors
+///
+/// If writing fails, an [`Error`] variant will be returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{binrw, helpers::{until_eof, write_until_eof}, io::Cursor, BinWrite};
+/// #[binrw]
+/// struct EntireFile {
+///     #[br(parse_with = until_eof)]
+///     #[bw(write_with = write_until_eof)]
+///     data: std::collections::VecDeque<u16>,
+/// }
+///
+/// # let mut x = Cursor::new(Vec::new());
+/// # EntireFile { data: [1, 2].into() }.write_be(&mut x).unwrap();
+/// # assert_eq!(x.into_inner(), b"\0\x01\0\x02");
+/// ```
+pub fn write_until_eof<Writer, T, V, Arg>(
+    values: &V,
+    writer: &mut Writer,
+    endian: Endian,
+    args: Arg,
+) -> BinResult<()>
+where
+    T: for<'a> BinWrite<Args<'a> = Arg>,
+    V: ?Sized,
+    for<'a> &'a V: IntoIterator<Item = &'a T>,
+    Writer: Write + Seek,
+    Arg: Clone,
+{
+    write_until_eof_with(T::write_options)(values, writer, endian, args)
+}
+
+/// Creates a writer that uses a given function to write every item of a
+/// collection, with nothing after the last item. This is the counterpart of
+/// [`until_eof_with`].
+///
+/// The given `write` function is called once for each item.
+///
+/// This helper can be used to write any collection type which can be iterated
+/// by reference.
+pub fn write_until_eof_with<Writer, T, V, Arg, WriteFn>(
+    write: WriteFn,
+) -> impl Fn(&V, &mut Writer, Endian, Arg) -> BinResult<()>
+where
+    V: ?Sized,
+    for<'a> &'a V: IntoIterator<Item = &'a T>,
+    Writer: Write + Seek,
+    Arg: Clone,
+    WriteFn: Fn(&T, &mut Writer, Endian, Arg) -> BinResult<()>,
+{
+    move |values, writer, endian, args| {
+        for value in values {
+            write(value, writer, endian, args.clone())?;
+        }
+        Ok(())
+    }
+}
+
 /// Creates a parser that builds a collection using items from the given
 /// iterable object as arguments for the parser.
 ///
@@ -422,6 +956,115 @@ where
     }
 }
 
+/// Creates a writer that writes the items of a collection, using items from
+/// the given iterable object as the arguments for each item. This is the
+/// counterpart of [`args_iter`].
+///
+/// This helper can be used to write any collection type which can be iterated
+/// by reference.
+///
+/// # Errors
+///
+/// If the number of items is different from the number of arguments, an
+/// [`AssertFail`](Error::AssertFail) error is returned. If writing fails, an
+/// [`Error`] variant will be returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{binrw, helpers::{args_iter, write_args_iter}, io::Cursor, BinWrite};
+/// #[binrw]
+/// #[brw(big, import_raw(size: u16))]
+/// struct Segment(
+///     #[br(count = size)]
+///     #[bw(assert(self_0.len() == usize::from(size)))]
+///     Vec<u8>
+/// );
+///
+/// #[binrw]
+/// #[brw(big)]
+/// struct Object {
+///     #[br(temp)]
+///     #[bw(calc = sizes.len() as u16)]
+///     count: u16,
+///     #[br(count = count)]
+///     sizes: Vec<u16>,
+///     #[br(parse_with = args_iter(sizes.iter().copied()))]
+///     #[bw(write_with = write_args_iter(sizes.iter().copied()))]
+///     segments: Vec<Segment>,
+/// }
+///
+/// # let mut x = Cursor::new(Vec::new());
+/// # let object = Object { sizes: vec![1, 2], segments: vec![Segment(vec![3]), Segment(vec![4, 5])] };
+/// # object.write(&mut x).unwrap();
+/// # assert_eq!(x.into_inner(), b"\0\x02\0\x01\0\x02\x03\x04\x05");
+/// ```
+pub fn write_args_iter<'a, W, T, V, Arg, It>(
+    it: It,
+) -> impl FnOnce(&V, &mut W, Endian, ()) -> BinResult<()>
+where
+    T: BinWrite<Args<'a> = Arg>,
+    V: ?Sized,
+    for<'b> &'b V: IntoIterator<Item = &'b T>,
+    W: Write + Seek,
+    It: IntoIterator<Item = Arg>,
+{
+    write_args_iter_with(it, |value: &T, writer, endian, arg| {
+        value.write_options(writer, endian, arg)
+    })
+}
+
+/// Creates a writer that uses a given function to write the items of a
+/// collection, using items from the given iterable object as arguments for the
+/// function. This is the counterpart of [`args_iter_with`].
+///
+/// The given `write` function is called once for each item.
+///
+/// This helper can be used to write any collection type which can be iterated
+/// by reference.
+///
+/// # Errors
+///
+/// If the number of items is different from the number of arguments, an
+/// [`AssertFail`](Error::AssertFail) error is returned. If writing fails, an
+/// [`Error`] variant will be returned.
+pub fn write_args_iter_with<Writer, T, V, Arg, It, WriteFn>(
+    it: It,
+    write: WriteFn,
+) -> impl FnOnce(&V, &mut Writer, Endian, ()) -> BinResult<()>
+where
+    V: ?Sized,
+    for<'a> &'a V: IntoIterator<Item = &'a T>,
+    Writer: Write + Seek,
+    It: IntoIterator<Item = Arg>,
+    WriteFn: Fn(&T, &mut Writer, Endian, Arg) -> BinResult<()>,
+{
+    move |values, writer, endian, ()| {
+        let mut values = values.into_iter();
+        let mut args = it.into_iter();
+        let mut count = 0;
+        loop {
+            match (values.next(), args.next()) {
+                (Some(value), Some(arg)) => write(value, writer, endian, arg)?,
+                (None, None) => return Ok(()),
+                (value, arg) => {
+                    let remaining_values = usize::from(value.is_some()) + values.count();
+                    let remaining_args = usize::from(arg.is_some()) + args.count();
+                    return Err(Error::AssertFail {
+                        pos: writer.stream_position()?,
+                        message: format!(
+                            "found {} items, but {} arguments",
+                            count + remaining_values,
+                            count + remaining_args
+                        ),
+                    });
+                }
+            }
+            count += 1;
+        }
+    }
+}
+
 /// Creates a parser that reads N items into a collection.
 ///
 /// This helper is similar to using `#[br(count = N)]` with [`Vec`], but is more
@@ -523,8 +1166,294 @@ where
     }
 }
 
+/// Creates a parser that reads items into a collection until exactly N bytes
+/// have been consumed.
+///
+/// This is useful for formats which give the size of a section in bytes
+/// instead of the number of elements in it.
+///
+/// This helper can be used to read into any collection type that implements
+/// [`FromIterator`].
+///
+/// # Errors
+///
+/// If the last item ends after the end of the section, an
+/// [`AssertFail`](Error::AssertFail) error is returned. If reading fails, an
+/// [`Error`] variant will be returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{BinRead, helpers::until_byte_len, io::Cursor, BinReaderExt};
+/// #[derive(BinRead)]
+/// struct Section {
+///     size: u8,
+///
+///     #[br(parse_with = until_byte_len(size.into()))]
+///     data: Vec<u16>,
+/// }
+///
+/// # let mut x = Cursor::new(b"\x04\0\x01\0\x02");
+/// # let x: Section = x.read_be().unwrap();
+/// # assert_eq!(x.data, &[1, 2]);
+/// # let mut x = Cursor::new(b"\x03\0\x01\0\x02");
+/// # assert!(x.read_be::<Section>().is_err());
+/// ```
+pub fn until_byte_len<R, T, Arg, Ret>(len: u64) -> impl Fn(&mut R, Endian, Arg) -> BinResult<Ret>
+where
+    T: for<'a> BinRead<Args<'a> = Arg>,
+    R: Read + Seek,
+    Arg: Clone,
+    Ret: FromIterator<T>,
+{
+    until_byte_len_with(len, T::read_options)
+}
+
+/// Creates a parser that uses a given function to read items into a
+/// collection until exactly N bytes have been consumed.
+///
+/// The given `read` function should return one item each time it is called.
+///
+/// This helper can be used to read into any collection type that implements
+/// [`FromIterator`].
+///
+/// # Errors
+///
+/// If the last item ends after the end of the section, an
+/// [`AssertFail`](Error::AssertFail) error is returned. If reading fails, an
+/// [`Error`] variant will be returned.
+pub fn until_byte_len_with<R, T, Arg, ReadFn, Ret>(
+    len: u64,
+    read: ReadFn,
+) -> impl Fn(&mut R, Endian, Arg) -> BinResult<Ret>
+where
+    R: Read + Seek,
+    Arg: Clone,
+    ReadFn: Fn(&mut R, Endian, Arg) -> BinResult<T>,
+    Ret: FromIterator<T>,
+{
+    move |reader, endian, args| {
+        let start = reader.stream_position()?;
+        let end = start.saturating_add(len);
+        let mut pos = start;
+        from_fn(|| {
+            (pos != end).then(|| {
+                let item_pos = pos;
+                let item = read(reader, endian, args.clone())?;
+                pos = reader.stream_position()?;
+
+                if pos > end {
+                    Err(Error::AssertFail {
+                        pos: item_pos,
+                        message: format!(
+                            "element at {:#x} ends {} bytes past the end of the {}-byte section",
+                            item_pos,
+                            pos - end,
+                            len
+                        ),
+                    })
+                } else if pos <= item_pos {
+                    Err(Error::AssertFail {
+                        pos: item_pos,
+                        message: format!("element at {item_pos:#x} did not consume any bytes"),
+                    })
+                } else {
+                    Ok(item)
+                }
+            })
+        })
+        .collect()
+    }
+}
+
+/// Returns the number of bytes which would be produced by writing a value.
+///
+/// The value is written to a sink which only counts bytes, so nothing is
+/// allocated. This is the write-side counterpart of [`until_byte_len`], and can
+/// be used to calculate the byte length of a section before writing it.
+///
+/// # Errors
+///
+/// If writing the value fails, an [`Error`] variant will be returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{binrw, helpers::{encoded_len, until_byte_len}, io::Cursor, prelude::*, Endian};
+/// #[binrw]
+/// #[brw(big)]
+/// struct Section {
+///     #[br(temp)]
+///     #[bw(try_calc = u8::try_from(encoded_len(data, Endian::Big, ())?))]
+///     size: u8,
+///
+///     #[br(parse_with = until_byte_len(size.into()))]
+///     data: Vec<u16>,
+/// }
+///
+/// # let mut x = Cursor::new(Vec::new());
+/// # Section { data: vec![1, 2] }.write(&mut x).unwrap();
+/// # assert_eq!(x.into_inner(), b"\x04\0\x01\0\x02");
+/// ```
+pub fn encoded_len<T>(value: &T, endian: Endian, args: T::Args<'_>) -> BinResult<u64>
+where
+    T: BinWrite + ?Sized,
+{
+    let mut counter = ByteCounter { pos: 0, len: 0 };
+    value.write_options(&mut counter, endian, args)?;
+    Ok(counter.len)
+}
+
+struct ByteCounter {
+    pos: u64,
+    len: u64,
+}
+
+impl Write for ByteCounter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.pos += buf.len() as u64;
+        self.len = self.len.max(self.pos);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for ByteCounter {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(pos) => Some(pos),
+            SeekFrom::Current(offset) => self.pos.checked_add_signed(offset),
+            SeekFrom::End(offset) => self.len.checked_add_signed(offset),
+        };
+
+        if let Some(pos) = pos {
+            self.pos = pos;
+            Ok(pos)
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            ))
+        }
+    }
+}
+
+/// Creates a parser that reads N unsigned integers of a given bit width, packed
+/// together with no padding between them, into a collection.
+///
+/// Bits are read using a [`BitReader`] with the given [`BitOrder`]. Any unused
+/// bits in the final byte are discarded, so the next field starts on a byte
+/// boundary.
+///
+/// This helper can be used to read into any collection type that implements
+/// [`FromIterator`], with any item type that can be converted from a `u64`.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{BinRead, helpers::read_packed, io::{BitOrder, Cursor}, BinReaderExt};
+/// #[derive(BinRead)]
+/// struct Packed {
+///     len: u8,
+///
+///     #[br(parse_with = read_packed(len.into(), 12, BitOrder::MsbFirst))]
+///     data: Vec<u16>,
+/// }
+///
+/// # let mut x = Cursor::new(b"\x03\xab\xcd\xef\x12\x30");
+/// # let x: Packed = x.read_be().unwrap();
+/// # assert_eq!(x.data, &[0xabc, 0xdef, 0x123]);
+/// ```
+pub fn read_packed<R, T, Ret>(
+    n: usize,
+    bits: u32,
+    order: BitOrder,
+) -> impl Fn(&mut R, Endian, ()) -> BinResult<Ret>
+where
+    R: Read + Seek,
+    T: TryFrom<u64>,
+    Ret: FromIterator<T>,
+{
+    move |reader, _, ()| {
+        let mut reader = BitReader::new(reader, order);
+        core::iter::repeat_with(|| {
+            let value = reader.read_bits(bits)?;
+            T::try_from(value).or_else(|_| {
+                Err(Error::AssertFail {
+                    pos: reader.stream_position()?,
+                    message: format!("packed value {value:#x} does not fit in the item type"),
+                })
+            })
+        })
+        .take(n)
+        .collect()
+    }
+}
+
+/// Creates a writer that writes a collection of unsigned integers with a given
+/// bit width, packed together with no padding between them.
+///
+/// Bits are written using a [`BitWriter`] with the given [`BitOrder`]. Any
+/// unused bits in the final byte are set to zero, so the next field starts on
+/// a byte boundary.
+///
+/// This helper can be used to write any collection which can be viewed as a
+/// slice, with any item type that can be converted into a `u64`.
+///
+/// # Errors
+///
+/// If an item does not fit in the given number of bits, an
+/// [`AssertFail`](Error::AssertFail) error is returned.
+///
+/// # Examples
+///
+/// ```
+/// # use binrw::{BinWrite, helpers::write_packed, io::{BitOrder, Cursor}};
+/// #[derive(BinWrite)]
+/// #[bw(big)]
+/// struct Packed {
+///     #[bw(write_with = write_packed(12, BitOrder::MsbFirst))]
+///     data: Vec<u16>,
+/// }
+///
+/// # let mut x = Cursor::new(Vec::new());
+/// # Packed { data: vec![0xabc, 0xdef, 0x123] }.write(&mut x).unwrap();
+/// # assert_eq!(x.into_inner(), b"\xab\xcd\xef\x12\x30");
+/// ```
+pub fn write_packed<W, T, V>(
+    bits: u32,
+    order: BitOrder,
+) -> impl Fn(&V, &mut W, Endian, ()) -> BinResult<()>
+where
+    W: Write + Seek,
+    T: Copy + Into<u64>,
+    V: AsRef<[T]> + ?Sized,
+{
+    move |values, writer, _, ()| {
+        let mut writer = BitWriter::new(writer, order);
+        for value in values.as_ref() {
+            let value = (*value).into();
+            if value.checked_shr(bits).unwrap_or(0) != 0 {
+                return Err(Error::AssertFail {
+                    pos: writer.stream_position()?,
+                    message: format!("packed value {value:#x} does not fit in {bits} bits"),
+                });
+            }
+            writer.write_bits(value, bits)?;
+        }
+        writer.into_inner()?;
+        Ok(())
+    }
+}
+
 /// Reads a 24-bit unsigned integer.
 ///
+/// This is equivalent to reading a [`U24`](crate::num::U24) and converting it
+/// into a `u32`.
+///
 /// # Errors
 ///
 /// If reading fails, an [`Error`](crate::Error) variant will be returned.
@@ -552,18 +1481,14 @@ where
 /// ```
 #[binrw::parser(reader, endian)]
 pub fn read_u24() -> binrw::BinResult<u32> {
-    type ConvFn = fn([u8; 4]) -> u32;
-    let mut buf = [0u8; 4];
-    let (conv, out): (ConvFn, &mut [u8]) = match endian {
-        Endian::Little => (u32::from_le_bytes, &mut buf[..3]),
-        Endian::Big => (u32::from_be_bytes, &mut buf[1..]),
-    };
-    reader.read_exact(out)?;
-    Ok(conv(buf))
+    crate::num::U24::read_options(reader, endian, ()).map(u32::from)
 }
 
 /// Writes a 24-bit unsigned integer.
 ///
+/// The upper 8 bits of the value are ignored. Use a
+/// [`U24`](crate::num::U24) field instead to check that the value is in range.
+///
 /// # Errors
 ///
 /// If writing fails, an [`Error`](crate::Error) variant will be returned.
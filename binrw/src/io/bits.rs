@@ -0,0 +1,396 @@
+//! Wrapper types for reading and writing bit streams.
+
+use super::{Error, ErrorKind, Read, Seek, SeekFrom, Write};
+
+/// The order in which bits are packed into bytes by [`BitReader`] and
+/// [`BitWriter`].
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum BitOrder {
+    /// Bits are packed starting from the most significant bit of each byte,
+    /// and the first bit of a value is its most significant bit.
+    ///
+    /// The 12-bit values `0xABC` and `0xDEF` are packed as `AB CD EF`.
+    MsbFirst,
+    /// Bits are packed starting from the least significant bit of each byte,
+    /// and the first bit of a value is its least significant bit.
+    ///
+    /// The 12-bit values `0xABC` and `0xDEF` are packed as `BC FA DE`.
+    LsbFirst,
+}
+
+/// The maximum number of bits which can be read or written in one call.
+const MAX_BITS: u32 = u64::BITS;
+
+fn too_many_bits() -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        "cannot read or write more than 64 bits at once",
+    )
+}
+
+/// A wrapper that reads individual bits from a stream.
+///
+/// Bits are read from one byte of the wrapped stream at a time. The
+/// [`Read`] implementation reads whole bytes starting from the current bit, so
+/// `BitReader` can be used with [`map_stream`] and ordinary [`BinRead`] types.
+///
+/// The position of a `BitReader` is the position of the wrapped stream, so a
+/// partially read byte is counted as having been read. Seeking discards any
+/// unread bits from the current byte.
+///
+/// # Examples
+///
+/// ```
+/// use binrw::io::{BitOrder, BitReader, Cursor};
+///
+/// let mut reader = BitReader::new(Cursor::new(b"\xab\xcd\xef"), BitOrder::MsbFirst);
+/// assert_eq!(reader.read_bits(12).unwrap(), 0xabc);
+/// assert!(reader.read_bit().unwrap());
+/// assert_eq!(reader.read_bits(3).unwrap(), 0b101);
+/// assert_eq!(reader.read_bits(8).unwrap(), 0xef);
+/// ```
+///
+/// [`BinRead`]: crate::BinRead
+/// [`map_stream`]: crate::docs::attribute#stream-access-and-manipulation
+pub struct BitReader<T> {
+    /// The original stream.
+    inner: T,
+    /// The bit packing order.
+    order: BitOrder,
+    /// The most recently read byte from the wrapped stream.
+    byte: u8,
+    /// The number of bits in `byte` which have not been read yet.
+    bits: u32,
+}
+
+impl<T> BitReader<T> {
+    /// Creates a new bit reader for the given stream.
+    pub fn new(inner: T, order: BitOrder) -> Self {
+        BitReader {
+            inner,
+            order,
+            byte: 0,
+            bits: 0,
+        }
+    }
+
+    /// Returns the bit packing order of this reader.
+    pub fn order(&self) -> BitOrder {
+        self.order
+    }
+
+    /// Returns `true` if the reader is at the start of a byte.
+    pub fn is_aligned(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// Discards the unread bits of the current byte, if any.
+    pub fn align(&mut self) {
+        self.bits = 0;
+    }
+
+    /// Gets a mutable reference to the underlying value.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Gets a reference to the underlying value.
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Consumes this wrapper, returning the underlying value. Any unread bits
+    /// of the current byte are discarded.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: Read> BitReader<T> {
+    /// Reads a single bit.
+    ///
+    /// # Errors
+    ///
+    /// If reading from the underlying stream fails, an error is returned.
+    pub fn read_bit(&mut self) -> super::Result<bool> {
+        self.read_bits(1).map(|bit| bit != 0)
+    }
+
+    /// Reads `count` bits and returns them as the low bits of an integer.
+    ///
+    /// # Errors
+    ///
+    /// If `count` is greater than 64, or if reading from the underlying stream
+    /// fails, an error is returned. Bits which were consumed before a read
+    /// failure are not restored.
+    pub fn read_bits(&mut self, count: u32) -> super::Result<u64> {
+        if count > MAX_BITS {
+            return Err(too_many_bits());
+        }
+
+        let mut value = 0;
+        let mut done = 0;
+        while done < count {
+            if self.bits == 0 {
+                let mut byte = [0];
+                self.inner.read_exact(&mut byte)?;
+                self.byte = byte[0];
+                self.bits = 8;
+            }
+
+            let take = (count - done).min(self.bits);
+            let mask = (1 << take) - 1;
+            match self.order {
+                BitOrder::MsbFirst => {
+                    let chunk = u64::from(self.byte >> (self.bits - take)) & mask;
+                    value = (value << take) | chunk;
+                }
+                BitOrder::LsbFirst => {
+                    let chunk = u64::from(self.byte >> (8 - self.bits)) & mask;
+                    value |= chunk << done;
+                }
+            }
+            self.bits -= take;
+            done += take;
+        }
+
+        Ok(value)
+    }
+}
+
+impl<T: Read> Read for BitReader<T> {
+    fn read(&mut self, buf: &mut [u8]) -> super::Result<usize> {
+        if self.is_aligned() {
+            return self.inner.read(buf);
+        }
+
+        // Each output byte is made from the unread bits of the current byte
+        // and the leading bits of the next byte, which then becomes the
+        // current byte, so the number of unread bits stays the same and a
+        // short read never loses any bits
+        let bits = self.bits;
+        for (i, out) in buf.iter_mut().enumerate() {
+            let mut next = [0];
+            if self.inner.read(&mut next)? == 0 {
+                return Ok(i);
+            }
+            let [next] = next;
+            *out = match self.order {
+                BitOrder::MsbFirst => (self.byte << (8 - bits)) | (next >> bits),
+                BitOrder::LsbFirst => (self.byte >> (8 - bits)) | (next << bits),
+            };
+            self.byte = next;
+        }
+
+        Ok(buf.len())
+    }
+}
+
+impl<T: Seek> Seek for BitReader<T> {
+    fn seek(&mut self, pos: SeekFrom) -> super::Result<u64> {
+        self.align();
+        self.inner.seek(pos)
+    }
+
+    fn stream_position(&mut self) -> super::Result<u64> {
+        self.inner.stream_position()
+    }
+}
+
+/// A wrapper that writes individual bits to a stream.
+///
+/// Bits are collected into a byte which is written to the wrapped stream once
+/// it is full. The [`Write`] implementation writes whole bytes starting from
+/// the current bit, so `BitWriter` can be used with [`map_stream`] and
+/// ordinary [`BinWrite`] types.
+///
+/// A partially filled byte is padded with zero bits and written when
+/// [`BitWriter::align`] is called, when the writer is seeked, and when the
+/// `BitWriter` is dropped. Errors which occur when the byte is written during
+/// drop are ignored; call [`BitWriter::align`] or [`BitWriter::into_inner`]
+/// before the `BitWriter` goes out of scope to handle them.
+///
+/// The position of a `BitWriter` counts a partially filled byte as having been
+/// written.
+///
+/// # Examples
+///
+/// ```
+/// use binrw::io::{BitOrder, BitWriter, Cursor};
+///
+/// let mut writer = BitWriter::new(Cursor::new(Vec::new()), BitOrder::MsbFirst);
+/// writer.write_bits(0xabc, 12).unwrap();
+/// writer.write_bit(true).unwrap();
+/// writer.write_bits(0b101, 3).unwrap();
+/// writer.write_bits(0xe, 4).unwrap();
+/// let out = writer.into_inner().unwrap();
+/// assert_eq!(out.into_inner(), b"\xab\xcd\xe0");
+/// ```
+///
+/// [`BinWrite`]: crate::BinWrite
+/// [`map_stream`]: crate::docs::attribute#stream-access-and-manipulation
+pub struct BitWriter<T: Write> {
+    // This is only `None` after `into_inner` has taken the stream, so that the
+    // `Drop` implementation knows not to write.
+    inner: Option<T>,
+    /// The bit packing order.
+    order: BitOrder,
+    /// The partially filled byte.
+    byte: u8,
+    /// The number of bits in `byte` which have been filled.
+    bits: u32,
+}
+
+impl<T: Write> BitWriter<T> {
+    /// Creates a new bit writer for the given stream.
+    pub fn new(inner: T, order: BitOrder) -> Self {
+        BitWriter {
+            inner: Some(inner),
+            order,
+            byte: 0,
+            bits: 0,
+        }
+    }
+
+    /// Returns the bit packing order of this writer.
+    pub fn order(&self) -> BitOrder {
+        self.order
+    }
+
+    /// Returns `true` if the writer is at the start of a byte.
+    pub fn is_aligned(&self) -> bool {
+        self.bits == 0
+    }
+
+    /// Pads the current byte with zero bits and writes it, if it has been
+    /// partially filled.
+    ///
+    /// # Errors
+    ///
+    /// If writing to the underlying stream fails, an error is returned.
+    pub fn align(&mut self) -> super::Result<()> {
+        if self.bits != 0 {
+            let byte = self.byte;
+            self.get_mut().write_all(&[byte])?;
+            self.byte = 0;
+            self.bits = 0;
+        }
+        Ok(())
+    }
+
+    /// Gets a mutable reference to the underlying value.
+    // Lint: Does not panic; `inner` is only `None` after `into_inner`, which
+    // consumes `self`.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn get_mut(&mut self) -> &mut T {
+        self.inner.as_mut().unwrap()
+    }
+
+    /// Gets a reference to the underlying value.
+    // Lint: Does not panic; `inner` is only `None` after `into_inner`, which
+    // consumes `self`.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn get_ref(&self) -> &T {
+        self.inner.as_ref().unwrap()
+    }
+
+    /// Writes any partially filled byte, then consumes this wrapper, returning
+    /// the underlying value.
+    ///
+    /// # Errors
+    ///
+    /// If writing to the underlying stream fails, an error is returned.
+    // Lint: Does not panic; `inner` is only `None` after `into_inner`, which
+    // consumes `self`.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn into_inner(mut self) -> super::Result<T> {
+        self.align()?;
+        Ok(self.inner.take().unwrap())
+    }
+
+    /// Writes a single bit.
+    ///
+    /// # Errors
+    ///
+    /// If writing to the underlying stream fails, an error is returned.
+    pub fn write_bit(&mut self, bit: bool) -> super::Result<()> {
+        self.write_bits(bit.into(), 1)
+    }
+
+    /// Writes the low `count` bits of `value`. Any higher bits are ignored.
+    ///
+    /// # Errors
+    ///
+    /// If `count` is greater than 64, or if writing to the underlying stream
+    /// fails, an error is returned.
+    pub fn write_bits(&mut self, value: u64, count: u32) -> super::Result<()> {
+        if count > MAX_BITS {
+            return Err(too_many_bits());
+        }
+
+        let mut done = 0;
+        while done < count {
+            let take = (count - done).min(8 - self.bits);
+            let mask = (1 << take) - 1;
+            // Lint: `chunk` is masked to at most 8 bits
+            #[allow(clippy::cast_possible_truncation)]
+            match self.order {
+                BitOrder::MsbFirst => {
+                    let chunk = ((value >> (count - done - take)) & mask) as u8;
+                    self.byte |= chunk << (8 - self.bits - take);
+                }
+                BitOrder::LsbFirst => {
+                    let chunk = ((value >> done) & mask) as u8;
+                    self.byte |= chunk << self.bits;
+                }
+            }
+            self.bits += take;
+            done += take;
+
+            if self.bits == 8 {
+                self.align()?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: Write> Write for BitWriter<T> {
+    fn write(&mut self, buf: &[u8]) -> super::Result<usize> {
+        if self.is_aligned() {
+            return self.get_mut().write(buf);
+        }
+
+        for byte in buf {
+            self.write_bits((*byte).into(), 8)?;
+        }
+
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> super::Result<()> {
+        self.get_mut().flush()
+    }
+}
+
+impl<T: Write + Seek> Seek for BitWriter<T> {
+    fn seek(&mut self, pos: SeekFrom) -> super::Result<u64> {
+        self.align()?;
+        self.get_mut().seek(pos)
+    }
+
+    fn stream_position(&mut self) -> super::Result<u64> {
+        let pos = self.get_mut().stream_position()?;
+        Ok(pos + u64::from(!self.is_aligned()))
+    }
+}
+
+impl<T: Write> Drop for BitWriter<T> {
+    fn drop(&mut self) {
+        if self.inner.is_some() {
+            // Dropping cannot report errors, so they are ignored
+            let _ = self.align();
+        }
+    }
+}
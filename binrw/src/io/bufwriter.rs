@@ -0,0 +1,256 @@
+//! Wrapper type to add buffering to write streams.
+
+use super::{Error, ErrorKind, Seek, SeekFrom, Write};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+/// The default buffer capacity, in bytes.
+const DEFAULT_BUF_SIZE: usize = 8 * 1024;
+
+/// A wrapper to add buffering to a write stream.
+///
+/// Unlike [`std::io::BufWriter`], this wrapper does not flush the write buffer
+/// every time a [`Seek`] method is called. The buffer holds a contiguous
+/// region of the stream, and seeking only moves a virtual position, so seeking
+/// backwards to overwrite earlier data (as [`restore_position`] does) or
+/// forwards to the end of the buffered data does not cause any I/O. The buffer
+/// is written to the wrapped stream when a write falls outside of the region
+/// it holds, when it is full, when [`Write::flush`] is called, and when the
+/// `BufWriter` is dropped.
+///
+/// # Limitations
+///
+/// Writing or seeking the wrapped stream object directly will cause an
+/// inconsistency in the internal state of the `BufWriter`. Call
+/// [`BufWriter::flush_buffer`] before accessing the wrapped stream directly.
+///
+/// Errors which occur when the buffer is written during drop are ignored. Call
+/// [`Write::flush`] or [`BufWriter::into_inner`] before the `BufWriter` goes
+/// out of scope to handle them.
+///
+/// [`restore_position`]: crate::docs::attribute#restore-position
+pub struct BufWriter<T: Write + Seek> {
+    // This is only `None` after `into_inner` has taken the stream, so that the
+    // `Drop` implementation knows not to flush.
+    inner: Option<T>,
+    buf: Vec<u8>,
+    capacity: usize,
+    /// The stream position of the first byte of `buf`.
+    buf_pos: u64,
+    /// The virtual position of the stream, or `None` if it has not been
+    /// retrieved from the wrapped stream yet.
+    pos: Option<u64>,
+    /// The cached position of the wrapped stream, or `None` if it is unknown.
+    inner_pos: Option<u64>,
+}
+
+impl<T: Write + Seek> BufWriter<T> {
+    /// Creates a new `BufWriter<T>` with a default buffer capacity.
+    pub fn new(inner: T) -> BufWriter<T> {
+        BufWriter::with_capacity(DEFAULT_BUF_SIZE, inner)
+    }
+
+    /// Creates a new `BufWriter<T>` with the specified buffer capacity.
+    pub fn with_capacity(capacity: usize, inner: T) -> BufWriter<T> {
+        BufWriter {
+            inner: Some(inner),
+            buf: Vec::with_capacity(capacity),
+            capacity,
+            buf_pos: 0,
+            pos: None,
+            inner_pos: None,
+        }
+    }
+
+    /// Returns a reference to the internally buffered data.
+    pub fn buffer(&self) -> &[u8] {
+        &self.buf
+    }
+
+    /// Returns the number of bytes the internal buffer can hold at once.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Gets a reference to the underlying writer.
+    // Lint: Does not panic; `inner` is only `None` after `into_inner`, which
+    // consumes `self`.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn get_ref(&self) -> &T {
+        self.inner.as_ref().unwrap()
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    ///
+    /// It is inadvisable to directly write to or seek the underlying writer
+    /// without calling [`BufWriter::flush_buffer`] first, as the buffered data
+    /// and cached position information will become inconsistent with it.
+    // Lint: Does not panic; `inner` is only `None` after `into_inner`, which
+    // consumes `self`.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn get_mut(&mut self) -> &mut T {
+        self.inner.as_mut().unwrap()
+    }
+
+    /// Writes the buffered data to the underlying writer and moves the
+    /// underlying writer to the current position of this `BufWriter`.
+    ///
+    /// Unlike [`Write::flush`], this does not flush the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing or seeking the underlying writer fails.
+    pub fn flush_buffer(&mut self) -> super::Result<()> {
+        self.write_buffer()?;
+        let pos = self.stream_position()?;
+        self.seek_inner(pos)
+    }
+
+    /// Writes the buffered data, then unwraps this `BufWriter<T>`, returning
+    /// the underlying writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing the buffered data fails. The `BufWriter`
+    /// is dropped in this case.
+    // Lint: Does not panic; `inner` is only `None` after `into_inner`, which
+    // consumes `self`.
+    #[allow(clippy::missing_panics_doc)]
+    pub fn into_inner(mut self) -> super::Result<T> {
+        self.flush_buffer()?;
+        Ok(self.inner.take().unwrap())
+    }
+
+    /// Writes the buffered data to the underlying writer, leaving the
+    /// underlying writer positioned after it.
+    fn write_buffer(&mut self) -> super::Result<()> {
+        if !self.buf.is_empty() {
+            self.seek_inner(self.buf_pos)?;
+            let result = self.inner.as_mut().unwrap().write_all(&self.buf);
+            // The position of the underlying writer is unknown if the write
+            // failed part way through
+            self.inner_pos = match result {
+                Ok(()) => Some(self.buf_pos + self.buf.len() as u64),
+                Err(_) => None,
+            };
+            result?;
+            self.buf.clear();
+        }
+
+        Ok(())
+    }
+
+    /// Moves the underlying writer to the given position, if it is not already
+    /// there.
+    fn seek_inner(&mut self, pos: u64) -> super::Result<()> {
+        if self.inner_pos != Some(pos) {
+            self.inner_pos = None;
+            let pos = self.get_mut().seek(SeekFrom::Start(pos))?;
+            self.inner_pos = Some(pos);
+        }
+
+        Ok(())
+    }
+
+    /// Returns the offset of the given position from the start of the buffer,
+    /// if data written at that position can be appended to the buffer without
+    /// leaving a gap.
+    fn buffer_offset(&self, pos: u64) -> Option<usize> {
+        let offset = usize::try_from(pos.checked_sub(self.buf_pos)?).ok()?;
+        (offset <= self.buf.len()).then_some(offset)
+    }
+}
+
+impl<T: Write + Seek> Write for BufWriter<T> {
+    fn write(&mut self, buf: &[u8]) -> super::Result<usize> {
+        let pos = self.stream_position()?;
+
+        let offset = match self.buffer_offset(pos) {
+            Some(offset) if offset + buf.len() <= self.capacity => offset,
+            _ => {
+                self.write_buffer()?;
+                if buf.len() >= self.capacity {
+                    self.seek_inner(pos)?;
+                    self.inner_pos = None;
+                    let n = self.get_mut().write(buf)?;
+                    self.inner_pos = Some(pos + n as u64);
+                    self.pos = self.inner_pos;
+                    return Ok(n);
+                }
+                self.buf_pos = pos;
+                0
+            }
+        };
+
+        let overlap = (self.buf.len() - offset).min(buf.len());
+        self.buf[offset..offset + overlap].copy_from_slice(&buf[..overlap]);
+        self.buf.extend_from_slice(&buf[overlap..]);
+        self.pos = Some(pos + buf.len() as u64);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> super::Result<()> {
+        self.flush_buffer()?;
+        self.get_mut().flush()
+    }
+}
+
+impl<T: Write + Seek> Seek for BufWriter<T> {
+    fn seek(&mut self, pos: SeekFrom) -> super::Result<u64> {
+        let n = match pos {
+            SeekFrom::Start(n) => Some(n),
+            SeekFrom::Current(rel_n) => {
+                let old = self.stream_position()?;
+                // https://github.com/rust-lang/rust/issues/87840
+                if rel_n >= 0 {
+                    // Lint: The sign is checked in precondition above
+                    #[allow(clippy::cast_sign_loss)]
+                    old.checked_add(rel_n as u64)
+                } else {
+                    old.checked_sub(rel_n.unsigned_abs())
+                }
+            }
+            SeekFrom::End(_) => {
+                // The end of the stream may be inside the buffered data, so
+                // it must be written before the underlying writer can say
+                // where the end is
+                self.write_buffer()?;
+                self.inner_pos = None;
+                let n = self.get_mut().seek(pos)?;
+                self.inner_pos = Some(n);
+                Some(n)
+            }
+        };
+
+        if let Some(n) = n {
+            self.pos = Some(n);
+            Ok(n)
+        } else {
+            Err(Error::new(
+                ErrorKind::InvalidInput,
+                "invalid seek to a negative or overflowing position",
+            ))
+        }
+    }
+
+    fn stream_position(&mut self) -> super::Result<u64> {
+        Ok(match self.pos {
+            None => {
+                let pos = self.get_mut().stream_position()?;
+                self.inner_pos = Some(pos);
+                self.pos = Some(pos);
+                pos
+            }
+            Some(pos) => pos,
+        })
+    }
+}
+
+impl<T: Write + Seek> Drop for BufWriter<T> {
+    fn drop(&mut self) {
+        if self.inner.is_some() {
+            // Dropping cannot report errors, so they are ignored
+            let _ = self.write_buffer();
+        }
+    }
+}
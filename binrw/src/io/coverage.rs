@@ -0,0 +1,184 @@
+//! Wrapper type that records which parts of a stream have been read.
+
+use super::{Read, Seek, SeekFrom};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::ops::Range;
+
+/// A wrapper that records every byte range read from a stream.
+///
+/// This is useful when reverse engineering a file format to find the parts of
+/// a file which are not parsed by any type. After reading, the [`unread`]
+/// and [`unread_to_end`] methods report the gaps between the ranges which were
+/// read.
+///
+/// # Examples
+///
+/// ```
+/// use binrw::{io::{CoverageReader, Cursor}, BinRead};
+///
+/// #[derive(BinRead)]
+/// #[br(little)]
+/// struct Header {
+///     magic: u16,
+///     #[br(pad_before = 2)]
+///     len: u16,
+///     #[br(seek_before = binrw::io::SeekFrom::Start(8))]
+///     flags: u8,
+/// }
+///
+/// let mut reader = CoverageReader::new(Cursor::new(b"\x01\x02????\x03\x04\x05??"));
+/// Header::read(&mut reader).unwrap();
+/// assert_eq!(reader.read_ranges(), &[0..2, 4..6, 8..9]);
+/// assert_eq!(reader.unread_to_end().unwrap(), vec![2..4, 6..8, 9..11]);
+/// ```
+///
+/// [`unread`]: Self::unread
+/// [`unread_to_end`]: Self::unread_to_end
+pub struct CoverageReader<T> {
+    /// The original stream.
+    inner: T,
+    /// The sorted, non-overlapping, non-adjacent ranges which have been read.
+    ranges: Vec<Range<u64>>,
+    /// The cached position of the stream, or `None` if it is unknown.
+    pos: Option<u64>,
+}
+
+impl<T> CoverageReader<T> {
+    /// Creates a new coverage-tracking wrapper for the given stream.
+    pub fn new(inner: T) -> Self {
+        CoverageReader {
+            inner,
+            ranges: Vec::new(),
+            pos: None,
+        }
+    }
+
+    /// Returns the byte ranges which have been read, in ascending order.
+    ///
+    /// Overlapping and adjacent ranges are merged.
+    pub fn read_ranges(&self) -> &[Range<u64>] {
+        &self.ranges
+    }
+
+    /// Returns the byte ranges between the start of the stream and `len` which
+    /// have not been read, in ascending order.
+    pub fn unread(&self, len: u64) -> Vec<Range<u64>> {
+        let mut gaps = Vec::new();
+        let mut start = 0;
+        for range in &self.ranges {
+            if range.start >= len {
+                break;
+            }
+            if range.start > start {
+                gaps.push(start..range.start);
+            }
+            start = range.end;
+        }
+        if start < len {
+            gaps.push(start..len);
+        }
+        gaps
+    }
+
+    /// Forgets all recorded ranges.
+    pub fn clear(&mut self) {
+        self.ranges.clear();
+    }
+
+    /// Gets a mutable reference to the underlying value.
+    ///
+    /// Reads made directly from the underlying value are not recorded. It is
+    /// inadvisable to directly seek the underlying value, as the cached
+    /// position information will become inconsistent with it.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Gets a reference to the underlying value.
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Consumes this wrapper, returning the underlying value.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+
+    /// Records that the given range has been read.
+    fn insert(&mut self, range: Range<u64>) {
+        if range.is_empty() {
+            return;
+        }
+
+        // Sequential reads are the common case, so are handled without a
+        // search
+        if let Some(last) = self.ranges.last_mut() {
+            if last.end == range.start {
+                last.end = range.end;
+                return;
+            } else if last.end < range.start {
+                self.ranges.push(range);
+                return;
+            }
+        }
+
+        let first = self.ranges.partition_point(|r| r.end < range.start);
+        let last = self.ranges.partition_point(|r| r.start <= range.end);
+        let merged = if first < last {
+            self.ranges[first].start.min(range.start)..self.ranges[last - 1].end.max(range.end)
+        } else {
+            range
+        };
+        self.ranges.splice(first..last, core::iter::once(merged));
+    }
+}
+
+impl<T: Seek> CoverageReader<T> {
+    /// Returns the byte ranges between the start and the end of the stream
+    /// which have not been read, in ascending order.
+    ///
+    /// # Errors
+    ///
+    /// If seeking the underlying stream fails, an error is returned.
+    pub fn unread_to_end(&mut self) -> super::Result<Vec<Range<u64>>> {
+        let pos = self.stream_position()?;
+        self.pos = None;
+        let len = self.inner.seek(SeekFrom::End(0))?;
+        self.seek(SeekFrom::Start(pos))?;
+        Ok(self.unread(len))
+    }
+}
+
+impl<T: Read + Seek> Read for CoverageReader<T> {
+    fn read(&mut self, buf: &mut [u8]) -> super::Result<usize> {
+        let start = self.stream_position()?;
+        // The position is unknown if the read fails part way through
+        self.pos = None;
+        let n = self.inner.read(buf)?;
+        let end = start + n as u64;
+        self.pos = Some(end);
+        self.insert(start..end);
+        Ok(n)
+    }
+}
+
+impl<T: Seek> Seek for CoverageReader<T> {
+    fn seek(&mut self, pos: SeekFrom) -> super::Result<u64> {
+        self.pos = None;
+        let pos = self.inner.seek(pos)?;
+        self.pos = Some(pos);
+        Ok(pos)
+    }
+
+    fn stream_position(&mut self) -> super::Result<u64> {
+        Ok(match self.pos {
+            None => {
+                let pos = self.inner.stream_position()?;
+                self.pos = Some(pos);
+                pos
+            }
+            Some(pos) => pos,
+        })
+    }
+}
@@ -4,22 +4,28 @@
 //! used by binrw. In `no_std` environments, a compatible subset API is exposed
 //! instead.
 
+mod bits;
 #[cfg(feature = "std")]
 mod bufreader;
+mod bufwriter;
+mod coverage;
 #[cfg(not(feature = "std"))]
 mod no_std;
 pub mod prelude;
 mod seek;
 mod take_seek;
 
+pub use bits::{BitOrder, BitReader, BitWriter};
 #[cfg(feature = "std")]
 pub use bufreader::BufReader;
 #[cfg(all(doc, not(feature = "std")))]
 #[doc(hidden)]
 pub struct BufReader;
+pub use bufwriter::BufWriter;
+pub use coverage::CoverageReader;
 #[cfg(not(feature = "std"))]
 pub use no_std::*;
-pub use seek::NoSeek;
+pub use seek::{NoSeek, NoSeekWriter};
 #[cfg(feature = "std")]
 pub use std::io::{Bytes, Cursor, Error, ErrorKind, Read, Result, Seek, SeekFrom, Write};
 pub use take_seek::*;
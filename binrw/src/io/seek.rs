@@ -1,4 +1,4 @@
-//! Wrapper type that provides a fake [`Seek`](crate::io::Seek) implementation.
+//! Wrapper types that provide fake [`Seek`](crate::io::Seek) implementations.
 
 use super::{Error, ErrorKind, SeekFrom};
 #[cfg(not(feature = "std"))]
@@ -111,3 +111,105 @@ impl<T: super::Write> super::Write for NoSeek<T> {
         Ok(())
     }
 }
+
+/// A wrapper that provides a limited implementation of
+/// [`Seek`](crate::io::Seek) for unseekable [`Write`](crate::io::Write)
+/// streams, emulating forward seeks by writing zeroes.
+///
+/// Unlike [`NoSeek`], seeking forward from the current position succeeds, so
+/// types which use [`seek_before`] or custom writers to skip forward over
+/// unused space can be written directly to sockets, compressors, and other
+/// sinks which cannot seek. Seeking backwards, as done by
+/// [`restore_position`] and other back-patching, returns an error.
+///
+/// Since everything before the current position has already been written, the
+/// current position is also the end of the stream, so seeking from the end
+/// behaves the same as seeking from the current position.
+///
+/// [`restore_position`]: crate::docs::attribute#restore-position
+/// [`seek_before`]: crate::docs::attribute#padding-and-alignment
+pub struct NoSeekWriter<T> {
+    /// The original stream.
+    inner: T,
+    /// The virtual position of the seekable stream.
+    pos: u64,
+}
+
+impl<T> NoSeekWriter<T> {
+    /// Creates a new seekable wrapper for the given writer.
+    pub fn new(inner: T) -> Self {
+        NoSeekWriter { inner, pos: 0 }
+    }
+
+    /// Gets a mutable reference to the underlying writer.
+    pub fn get_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Gets a reference to the underlying writer.
+    pub fn get_ref(&self) -> &T {
+        &self.inner
+    }
+
+    /// Consumes this wrapper, returning the underlying writer.
+    pub fn into_inner(self) -> T {
+        self.inner
+    }
+}
+
+impl<T: super::Write> super::Seek for NoSeekWriter<T> {
+    fn seek(&mut self, pos: SeekFrom) -> super::Result<u64> {
+        const ZEROES: [u8; 0x20] = [0; 0x20];
+
+        let target = match pos {
+            SeekFrom::Start(n) => Some(n),
+            SeekFrom::Current(n) | SeekFrom::End(n) => self.pos.checked_add_signed(n),
+        };
+
+        match target {
+            Some(target) if target >= self.pos => {
+                while self.pos < target {
+                    // Lint: The value is clamped to the length of `ZEROES`
+                    #[allow(clippy::cast_possible_truncation)]
+                    let len = (target - self.pos).min(ZEROES.len() as u64) as usize;
+                    super::Write::write_all(self, &ZEROES[..len])?;
+                }
+                Ok(target)
+            }
+            // https://github.com/rust-lang/rust/issues/86442
+            _ => Err(Error::new(
+                ErrorKind::Other,
+                "cannot seek backwards on unseekable writer",
+            )),
+        }
+    }
+
+    fn stream_position(&mut self) -> super::Result<u64> {
+        Ok(self.pos)
+    }
+}
+
+impl<T: super::Write> super::Write for NoSeekWriter<T> {
+    fn write(&mut self, buf: &[u8]) -> super::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.pos += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> super::Result<()> {
+        self.inner.flush()
+    }
+
+    #[cfg(feature = "std")]
+    fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> super::Result<usize> {
+        let n = self.inner.write_vectored(bufs)?;
+        self.pos += n as u64;
+        Ok(n)
+    }
+
+    fn write_all(&mut self, buf: &[u8]) -> super::Result<()> {
+        self.inner.write_all(buf)?;
+        self.pos += buf.len() as u64;
+        Ok(())
+    }
+}
@@ -30,6 +30,9 @@ pub mod helpers;
 pub mod io;
 pub mod meta;
 mod named_args;
+pub mod num;
+#[doc(hidden)]
+pub mod patchable;
 #[doc(hidden)]
 pub mod pos_value;
 pub mod punctuated;
@@ -46,8 +49,9 @@ pub use {
     error::Error,
     file_ptr::{FilePtr, FilePtr128, FilePtr16, FilePtr32, FilePtr64, FilePtr8},
     named_args::NamedArgs,
-    pos_value::PosValue,
-    strings::{NullString, NullWideString},
+    patchable::Patchable,
+    pos_value::{PosValue, Spanned},
+    strings::{Encoding, NullString, NullWideString, StringArgs},
 };
 
 /// Derive macro generating an impl of the trait [`BinRead`].
@@ -10,8 +10,11 @@
 //! [`BinWrite`]: crate::BinWrite
 
 use crate::Endian;
+#[cfg(target_has_atomic = "ptr")]
+use alloc::sync::Arc;
+use alloc::{borrow::Cow, rc::Rc};
 #[cfg(not(feature = "std"))]
-use alloc::{boxed::Box, vec::Vec};
+use alloc::{borrow::ToOwned, boxed::Box, vec::Vec};
 use core::marker::PhantomData;
 
 /// Types that require a magic number when parsed.
@@ -38,6 +41,20 @@ pub trait WriteMagic {
     const MAGIC: Self::MagicType;
 }
 
+/// Enums whose variants are selected by an externally supplied tag.
+///
+/// This trait is automatically defined on derived enums with a
+/// [`tag_type` directive](crate::docs::attribute#tag). It allows a parent
+/// object to compute the value of its tag field from the enum variant being
+/// written.
+pub trait WriteTag {
+    /// The type of the tag.
+    type TagType;
+
+    /// Returns the tag value of the current variant.
+    fn tag(&self) -> Self::TagType;
+}
+
 /// Types with explicit read endianness.
 ///
 /// This trait is automatically defined on derived types with a
@@ -95,11 +112,30 @@ macro_rules! endian_impl {
 
 endian_impl!(() i8 u8 core::num::NonZeroU8 core::num::NonZeroI8 crate::strings::NullString => EndianKind::None);
 
-impl<T: ReadEndian + ?Sized> ReadEndian for Box<T> {
-    const ENDIAN: EndianKind = <T as ReadEndian>::ENDIAN;
+macro_rules! endian_pointer_impl {
+    ($($Ty:ident)+) => {$(
+        impl<T: ReadEndian + ?Sized> ReadEndian for $Ty<T> {
+            const ENDIAN: EndianKind = <T as ReadEndian>::ENDIAN;
+        }
+
+        impl<T: WriteEndian + ?Sized> WriteEndian for $Ty<T> {
+            const ENDIAN: EndianKind = <T as WriteEndian>::ENDIAN;
+        }
+    )+}
+}
+
+endian_pointer_impl!(Box Rc);
+#[cfg(target_has_atomic = "ptr")]
+endian_pointer_impl!(Arc);
+
+impl<T: ToOwned + ?Sized> ReadEndian for Cow<'_, T>
+where
+    T::Owned: ReadEndian,
+{
+    const ENDIAN: EndianKind = <T::Owned as ReadEndian>::ENDIAN;
 }
 
-impl<T: WriteEndian + ?Sized> WriteEndian for Box<T> {
+impl<T: WriteEndian + ToOwned + ?Sized> WriteEndian for Cow<'_, T> {
     const ENDIAN: EndianKind = <T as WriteEndian>::ENDIAN;
 }
 
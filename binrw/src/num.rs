@@ -0,0 +1,694 @@
+//! Type definitions for non-native numeric formats.
+
+use crate::{
+    io::{Read, Seek, Write},
+    BinRead, BinResult, BinWrite, Endian,
+};
+use core::{cmp::Ordering, fmt};
+
+/// A binary16 ([half-precision]) floating point number.
+///
+/// Conversion to [`f32`] and [`f64`] is lossless. Conversion from `f32` and
+/// `f64` rounds to the nearest representable value, with ties to even, and
+/// values which are too large become infinity.
+///
+/// [half-precision]: https://en.wikipedia.org/wiki/Half-precision_floating-point_format
+///
+/// # Examples
+///
+/// ```
+/// use binrw::{BinRead, io::Cursor, num::F16};
+///
+/// #[derive(BinRead)]
+/// #[br(little)]
+/// struct Vertex {
+///     x: F16,
+///     y: F16,
+/// }
+///
+/// let vertex = Vertex::read(&mut Cursor::new(b"\x00\x3c\x00\xc1")).unwrap();
+/// assert_eq!(vertex.x.to_f32(), 1.0);
+/// assert_eq!(f32::from(vertex.y), -2.5);
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct F16(u16);
+
+/// A [bfloat16] floating point number.
+///
+/// Conversion to [`f32`] and [`f64`] is lossless. Conversion from `f32` and
+/// `f64` rounds to the nearest representable value, with ties to even, and
+/// values which are too large become infinity.
+///
+/// [bfloat16]: https://en.wikipedia.org/wiki/Bfloat16_floating-point_format
+///
+/// # Examples
+///
+/// ```
+/// use binrw::{BinReaderExt, io::Cursor, num::Bf16};
+///
+/// let value: Bf16 = Cursor::new(b"\x40\x49").read_be().unwrap();
+/// assert_eq!(value.to_f32(), 3.140625);
+/// ```
+#[derive(Clone, Copy, Default)]
+pub struct Bf16(u16);
+
+macro_rules! float_impl {
+    ($($name:ident, $exp_bits:literal, $man_bits:literal);* $(;)?) => {
+        $(
+            impl $name {
+                /// Creates a value from its raw bits.
+                #[must_use]
+                pub const fn from_bits(bits: u16) -> Self {
+                    Self(bits)
+                }
+
+                /// Returns the raw bits of the value.
+                #[must_use]
+                pub const fn to_bits(self) -> u16 {
+                    self.0
+                }
+
+                /// Converts an `f32` to the nearest representable value.
+                #[must_use]
+                pub fn from_f32(value: f32) -> Self {
+                    // Widening is exact, so this only rounds once
+                    Self::from_f64(value.into())
+                }
+
+                /// Converts an `f64` to the nearest representable value.
+                #[must_use]
+                pub fn from_f64(value: f64) -> Self {
+                    // Lint: The encoding is only `1 + exp_bits + man_bits` bits
+                    #[allow(clippy::cast_possible_truncation)]
+                    Self(encode_float(value, $exp_bits, $man_bits) as u16)
+                }
+
+                /// Converts the value to an `f32` without loss of precision.
+                #[must_use]
+                pub fn to_f32(self) -> f32 {
+                    // Lint: Every value is exactly representable as an `f32`
+                    #[allow(clippy::cast_possible_truncation)]
+                    let value = self.to_f64() as f32;
+                    value
+                }
+
+                /// Converts the value to an `f64` without loss of precision.
+                #[must_use]
+                pub fn to_f64(self) -> f64 {
+                    decode_float(self.0.into(), $exp_bits, $man_bits)
+                }
+            }
+
+            impl From<$name> for f32 {
+                fn from(value: $name) -> Self {
+                    value.to_f32()
+                }
+            }
+
+            impl From<$name> for f64 {
+                fn from(value: $name) -> Self {
+                    value.to_f64()
+                }
+            }
+
+            impl PartialEq for $name {
+                fn eq(&self, other: &Self) -> bool {
+                    self.to_f32() == other.to_f32()
+                }
+            }
+
+            impl PartialOrd for $name {
+                fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                    self.to_f32().partial_cmp(&other.to_f32())
+                }
+            }
+
+            impl fmt::Debug for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Debug::fmt(&self.to_f32(), f)
+                }
+            }
+
+            impl fmt::Display for $name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&self.to_f32(), f)
+                }
+            }
+
+            impl BinRead for $name {
+                type Args<'a> = ();
+
+                fn read_options<R: Read + Seek>(
+                    reader: &mut R,
+                    endian: Endian,
+                    (): Self::Args<'_>,
+                ) -> BinResult<Self> {
+                    u16::read_options(reader, endian, ()).map(Self)
+                }
+            }
+
+            impl BinWrite for $name {
+                type Args<'a> = ();
+
+                fn write_options<W: Write + Seek>(
+                    &self,
+                    writer: &mut W,
+                    endian: Endian,
+                    (): Self::Args<'_>,
+                ) -> BinResult<()> {
+                    self.0.write_options(writer, endian, ())
+                }
+            }
+        )*
+    };
+}
+
+float_impl! {
+    F16, 5, 10;
+    Bf16, 8, 7;
+}
+
+/// The number of explicit mantissa bits in an `f64`.
+const F64_MAN_BITS: u32 = 52;
+
+/// Encodes an `f64` as an IEEE 754 binary floating point number with the given
+/// number of exponent and mantissa bits, rounding to nearest with ties to even.
+fn encode_float(value: f64, exp_bits: u32, man_bits: u32) -> u64 {
+    let bits = value.to_bits();
+    let sign = (bits >> 63) << (exp_bits + man_bits);
+    let exp = (bits >> F64_MAN_BITS) & 0x7ff;
+    let man = bits & ((1 << F64_MAN_BITS) - 1);
+    let max_exp = (1 << exp_bits) - 1;
+
+    if exp == 0x7ff {
+        // Infinity stays infinity, and NaN stays NaN with the quiet bit set
+        // and as much of the payload as fits
+        let nan = if man == 0 {
+            0
+        } else {
+            (1 << (man_bits - 1)) | (man >> (F64_MAN_BITS - man_bits))
+        };
+        return sign | (max_exp << man_bits) | nan;
+    } else if exp == 0 {
+        // Subnormal `f64` values are far too small to be represented
+        return sign;
+    }
+
+    let exponent_bias = (1_i64 << (exp_bits - 1)) - 1;
+    // Lint: `exp` is at most 11 bits
+    #[allow(clippy::cast_possible_wrap)]
+    let target_exp = exp as i64 - 1023 + exponent_bias;
+    let shift = i64::from(F64_MAN_BITS - man_bits) + (1 - target_exp).max(0);
+    if shift > i64::from(F64_MAN_BITS) + 1 {
+        // Less than half of the smallest subnormal value
+        return sign;
+    }
+
+    // Lint: `shift` is positive and less than 64
+    #[allow(clippy::cast_sign_loss, clippy::cast_possible_truncation)]
+    let shift = shift as u32;
+    let sig = man | (1 << F64_MAN_BITS);
+    let mut out = sig >> shift;
+    let rem = sig & ((1 << shift) - 1);
+    let half = 1 << (shift - 1);
+    if rem > half || (rem == half && out & 1 == 1) {
+        out += 1;
+    }
+
+    // The implicit bit of `out` is added to the exponent field, so rounding up
+    // to the next power of two and from the largest subnormal value to the
+    // smallest normal value carry into the exponent
+    // Lint: `target_exp - 1` is clamped to be non-negative
+    #[allow(clippy::cast_sign_loss)]
+    let field = (((target_exp - 1).max(0) as u64) << man_bits) + out;
+    if field >= max_exp << man_bits {
+        sign | (max_exp << man_bits)
+    } else {
+        sign | field
+    }
+}
+
+/// Decodes an IEEE 754 binary floating point number with the given number of
+/// exponent and mantissa bits, which must be no larger than those of an `f64`.
+fn decode_float(bits: u64, exp_bits: u32, man_bits: u32) -> f64 {
+    let negative = bits >> (exp_bits + man_bits) & 1 == 1;
+    let exp = (bits >> man_bits) & ((1 << exp_bits) - 1);
+    let man = bits & ((1 << man_bits) - 1);
+    let max_exp = (1 << exp_bits) - 1;
+    let exponent_bias = (1 << (exp_bits - 1)) - 1;
+
+    let sign = u64::from(negative) << 63;
+    if exp == max_exp {
+        f64::from_bits(sign | (0x7ff << F64_MAN_BITS) | (man << (F64_MAN_BITS - man_bits)))
+    } else if exp == 0 {
+        // The value is `man * 2^(1 - exponent_bias - man_bits)`, and the scale is a
+        // normal `f64`, so this is exact
+        let scale =
+            f64::from_bits((1023 + 1 - exponent_bias - u64::from(man_bits)) << F64_MAN_BITS);
+        // Lint: `man` is at most `man_bits` bits
+        #[allow(clippy::cast_precision_loss)]
+        let value = man as f64 * scale;
+        if negative {
+            -value
+        } else {
+            value
+        }
+    } else {
+        f64::from_bits(
+            sign | ((exp + 1023 - exponent_bias) << F64_MAN_BITS)
+                | (man << (F64_MAN_BITS - man_bits)),
+        )
+    }
+}
+
+/// A binary [fixed-point] number stored as an integer of type `T` with
+/// `FRAC_BITS` fractional bits.
+///
+/// Conversion to [`f64`] is lossless, as is conversion to [`f32`] for 8- and
+/// 16-bit integer types. Conversion from `f32` and `f64` rounds to the nearest
+/// representable value, with ties away from zero; values which are out of
+/// range saturate, and NaN becomes zero.
+///
+/// `FRAC_BITS` must not be larger than the number of bits in `T`, or the
+/// program will fail to compile.
+///
+/// [fixed-point]: https://en.wikipedia.org/wiki/Fixed-point_arithmetic
+///
+/// # Examples
+///
+/// ```
+/// use binrw::{BinRead, io::Cursor, num::{Q16_16, Q2_14}};
+///
+/// #[derive(BinRead)]
+/// #[br(big)]
+/// struct Transform {
+///     scale: Q2_14,
+///     offset: Q16_16,
+/// }
+///
+/// let transform = Transform::read(&mut Cursor::new(b"\x60\x00\xff\xfe\x80\x00")).unwrap();
+/// assert_eq!(transform.scale.to_f32(), 1.5);
+/// assert_eq!(transform.offset.to_f64(), -1.5);
+/// ```
+#[derive(Clone, Copy, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Fixed<T, const FRAC_BITS: u32>(T);
+
+/// A signed Q16.16 fixed-point number, with 16 integer and 16 fractional bits.
+pub type Q16_16 = Fixed<i32, 16>;
+
+/// A signed Q8.8 fixed-point number, with 8 integer and 8 fractional bits.
+pub type Q8_8 = Fixed<i16, 8>;
+
+/// A signed 2.14 fixed-point number, with 2 integer and 14 fractional bits.
+pub type Q2_14 = Fixed<i16, 14>;
+
+impl<T, const FRAC_BITS: u32> Fixed<T, FRAC_BITS> {
+    const VALID_FRAC_BITS: () = assert!(
+        FRAC_BITS as usize <= core::mem::size_of::<T>() * 8,
+        "fractional bits must not be more than the number of bits in the value"
+    );
+
+    /// Creates a value from its raw integer representation.
+    pub const fn from_bits(bits: T) -> Self {
+        Self(bits)
+    }
+
+    /// Returns the raw integer representation of the value.
+    pub fn to_bits(self) -> T {
+        self.0
+    }
+
+    /// `2^FRAC_BITS`, which is exact as an `f64`.
+    fn scale() -> f64 {
+        f64::from_bits((1023 + u64::from(FRAC_BITS)) << F64_MAN_BITS)
+    }
+}
+
+/// Rounds to the nearest integer, with ties away from zero.
+///
+/// This is equivalent to `f64::round`, which is not available without `std`.
+/// Out of range values saturate, and NaN becomes zero.
+fn round_saturating(value: f64) -> i128 {
+    // Lint: Saturation is intended, and values which cannot be represented
+    // exactly are already integers
+    #[allow(clippy::cast_possible_truncation, clippy::cast_precision_loss)]
+    let (int, frac) = {
+        let int = value as i128;
+        (int, value - int as f64)
+    };
+    if frac >= 0.5 {
+        int.saturating_add(1)
+    } else if frac <= -0.5 {
+        int.saturating_sub(1)
+    } else {
+        int
+    }
+}
+
+macro_rules! fixed_impl {
+    ($($type_name:ty),* $(,)?) => {
+        $(
+            impl<const FRAC_BITS: u32> Fixed<$type_name, FRAC_BITS> {
+                /// Converts an `f32` to the nearest representable value.
+                #[must_use]
+                pub fn from_f32(value: f32) -> Self {
+                    Self::from_f64(value.into())
+                }
+
+                /// Converts an `f64` to the nearest representable value.
+                #[must_use]
+                pub fn from_f64(value: f64) -> Self {
+                    let () = Self::VALID_FRAC_BITS;
+                    let bits = round_saturating(value * Self::scale());
+                    Self(<$type_name>::try_from(bits).unwrap_or(if bits < 0 {
+                        <$type_name>::MIN
+                    } else {
+                        <$type_name>::MAX
+                    }))
+                }
+
+                /// Converts the value to an `f32`.
+                ///
+                /// This is lossless for 8- and 16-bit integer types.
+                #[must_use]
+                pub fn to_f32(self) -> f32 {
+                    // Lint: Rounding is documented
+                    #[allow(clippy::cast_possible_truncation)]
+                    let value = self.to_f64() as f32;
+                    value
+                }
+
+                /// Converts the value to an `f64` without loss of precision.
+                #[must_use]
+                pub fn to_f64(self) -> f64 {
+                    let () = Self::VALID_FRAC_BITS;
+                    f64::from(self.0) / Self::scale()
+                }
+            }
+
+            impl<const FRAC_BITS: u32> From<Fixed<$type_name, FRAC_BITS>> for f64 {
+                fn from(value: Fixed<$type_name, FRAC_BITS>) -> Self {
+                    value.to_f64()
+                }
+            }
+
+            impl<const FRAC_BITS: u32> fmt::Debug for Fixed<$type_name, FRAC_BITS> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Debug::fmt(&self.to_f64(), f)
+                }
+            }
+
+            impl<const FRAC_BITS: u32> fmt::Display for Fixed<$type_name, FRAC_BITS> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&self.to_f64(), f)
+                }
+            }
+
+            impl<const FRAC_BITS: u32> BinRead for Fixed<$type_name, FRAC_BITS> {
+                type Args<'a> = ();
+
+                fn read_options<R: Read + Seek>(
+                    reader: &mut R,
+                    endian: Endian,
+                    (): Self::Args<'_>,
+                ) -> BinResult<Self> {
+                    <$type_name>::read_options(reader, endian, ()).map(Self)
+                }
+            }
+
+            impl<const FRAC_BITS: u32> BinWrite for Fixed<$type_name, FRAC_BITS> {
+                type Args<'a> = ();
+
+                fn write_options<W: Write + Seek>(
+                    &self,
+                    writer: &mut W,
+                    endian: Endian,
+                    (): Self::Args<'_>,
+                ) -> BinResult<()> {
+                    self.0.write_options(writer, endian, ())
+                }
+            }
+        )*
+    };
+}
+
+fixed_impl!(u8, u16, u32, i8, i16, i32);
+
+macro_rules! fixed_f32_impl {
+    ($($type_name:ty),* $(,)?) => {
+        $(
+            impl<const FRAC_BITS: u32> From<Fixed<$type_name, FRAC_BITS>> for f32 {
+                fn from(value: Fixed<$type_name, FRAC_BITS>) -> Self {
+                    value.to_f32()
+                }
+            }
+        )*
+    };
+}
+
+fixed_f32_impl!(u8, u16, i8, i16);
+
+/// The error type returned when a checked conversion to or from an odd-width
+/// integer type fails.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct TryFromIntError(());
+
+impl fmt::Display for TryFromIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("out of range integral type conversion attempted")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryFromIntError {}
+
+macro_rules! odd_int_impl {
+    (
+        $(#[doc = $doc:literal])*
+        $name:ident($inner:ty, $bits:literal);
+        from($($from:ty),*);
+        try_from($($try_from:ty),*);
+        into($($into:ty),*);
+        try_into($($try_into:ty),*);
+    ) => {
+        $(#[doc = $doc])*
+        ///
+        /// Values are stored in
+        #[doc = concat!(stringify!($bits), " bits and are always in the range [`",
+            stringify!($name), "::MIN`] to [`", stringify!($name), "::MAX`].")]
+        /// Lossless conversions to and from native integers use [`From`], and
+        /// checked conversions use [`TryFrom`].
+        #[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        pub struct $name($inner);
+
+        impl $name {
+            /// The size of this integer type in bits.
+            pub const BITS: u32 = $bits;
+
+            /// The smallest value that can be represented by this integer type.
+            pub const MIN: Self = Self(<$inner>::MIN >> (<$inner>::BITS - $bits));
+
+            /// The largest value that can be represented by this integer type.
+            pub const MAX: Self = Self(<$inner>::MAX >> (<$inner>::BITS - $bits));
+
+            /// Creates a value from a native integer, or returns `None` if it
+            /// is out of range.
+            #[must_use]
+            pub const fn new(value: $inner) -> Option<Self> {
+                if value >= Self::MIN.0 && value <= Self::MAX.0 {
+                    Some(Self(value))
+                } else {
+                    None
+                }
+            }
+
+            /// Returns the value as a native integer.
+            #[must_use]
+            pub const fn get(self) -> $inner {
+                self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        $(
+            impl From<$from> for $name {
+                fn from(value: $from) -> Self {
+                    Self(value.into())
+                }
+            }
+        )*
+
+        $(
+            impl TryFrom<$try_from> for $name {
+                type Error = TryFromIntError;
+
+                fn try_from(value: $try_from) -> Result<Self, Self::Error> {
+                    <$inner>::try_from(value)
+                        .ok()
+                        .and_then(Self::new)
+                        .ok_or(TryFromIntError(()))
+                }
+            }
+        )*
+
+        $(
+            impl From<$name> for $into {
+                fn from(value: $name) -> Self {
+                    // Lint: The value is always in range of the target type
+                    #[allow(clippy::cast_lossless, clippy::cast_possible_wrap)]
+                    let value = value.0 as $into;
+                    value
+                }
+            }
+        )*
+
+        $(
+            impl TryFrom<$name> for $try_into {
+                type Error = TryFromIntError;
+
+                fn try_from(value: $name) -> Result<Self, Self::Error> {
+                    <$try_into>::try_from(value.0).map_err(|_| TryFromIntError(()))
+                }
+            }
+        )*
+
+        impl BinRead for $name {
+            type Args<'a> = ();
+
+            fn read_options<R: Read + Seek>(
+                reader: &mut R,
+                endian: Endian,
+                (): Self::Args<'_>,
+            ) -> BinResult<Self> {
+                const SIZE: usize = core::mem::size_of::<$inner>();
+                const BYTES: usize = $bits / 8;
+                let mut val = [0; SIZE];
+                let pos = reader.stream_position()?;
+
+                let (convert, buf): (fn([u8; SIZE]) -> $inner, _) = match endian {
+                    Endian::Big => (<$inner>::from_be_bytes, &mut val[SIZE - BYTES..]),
+                    Endian::Little => (<$inner>::from_le_bytes, &mut val[..BYTES]),
+                };
+                reader.read_exact(buf).or_else(crate::__private::restore_position(reader, pos))?;
+
+                // Shifting up and back down sign extends signed values
+                let shift = <$inner>::BITS - $bits;
+                Ok(Self((convert(val) << shift) >> shift))
+            }
+        }
+
+        impl BinWrite for $name {
+            type Args<'a> = ();
+
+            fn write_options<W: Write + Seek>(
+                &self,
+                writer: &mut W,
+                endian: Endian,
+                (): Self::Args<'_>,
+            ) -> BinResult<()> {
+                const SIZE: usize = core::mem::size_of::<$inner>();
+                const BYTES: usize = $bits / 8;
+                let (buf, range) = match endian {
+                    Endian::Big => (self.0.to_be_bytes(), SIZE - BYTES..SIZE),
+                    Endian::Little => (self.0.to_le_bytes(), 0..BYTES),
+                };
+                writer.write_all(&buf[range]).map_err(Into::into)
+            }
+        }
+    };
+}
+
+odd_int_impl! {
+    /// A 24-bit unsigned integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use binrw::{BinRead, io::Cursor, num::U24};
+    ///
+    /// #[derive(BinRead)]
+    /// #[br(big)]
+    /// struct Chunk {
+    ///     len: U24,
+    ///     #[br(count = len)]
+    ///     data: Vec<u8>,
+    /// }
+    ///
+    /// let chunk = Chunk::read(&mut Cursor::new(b"\0\0\x02\xab\xcd")).unwrap();
+    /// assert_eq!(u32::from(chunk.len), 2);
+    /// assert_eq!(chunk.data, [0xab, 0xcd]);
+    /// ```
+    U24(u32, 24);
+    from(u8, u16);
+    try_from(u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    into(u32, u64, u128, i32, i64, i128);
+    try_into(u8, u16, usize, i8, i16, isize);
+}
+
+odd_int_impl! {
+    /// A 24-bit signed integer.
+    I24(i32, 24);
+    from(u8, u16, i8, i16);
+    try_from(u32, u64, u128, usize, i32, i64, i128, isize);
+    into(i32, i64, i128);
+    try_into(u8, u16, u32, u64, u128, usize, i8, i16, isize);
+}
+
+odd_int_impl! {
+    /// A 40-bit unsigned integer.
+    U40(u64, 40);
+    from(u8, u16, u32);
+    try_from(u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    into(u64, u128, i64, i128);
+    try_into(u8, u16, u32, usize, i8, i16, i32, isize);
+}
+
+odd_int_impl! {
+    /// A 40-bit signed integer.
+    I40(i64, 40);
+    from(u8, u16, u32, i8, i16, i32);
+    try_from(u64, u128, usize, i64, i128, isize);
+    into(i64, i128);
+    try_into(u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+}
+
+odd_int_impl! {
+    /// A 48-bit unsigned integer.
+    U48(u64, 48);
+    from(u8, u16, u32);
+    try_from(u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    into(u64, u128, i64, i128);
+    try_into(u8, u16, u32, usize, i8, i16, i32, isize);
+}
+
+odd_int_impl! {
+    /// A 48-bit signed integer.
+    I48(i64, 48);
+    from(u8, u16, u32, i8, i16, i32);
+    try_from(u64, u128, usize, i64, i128, isize);
+    into(i64, i128);
+    try_into(u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+}
+
+odd_int_impl! {
+    /// A 56-bit unsigned integer.
+    U56(u64, 56);
+    from(u8, u16, u32);
+    try_from(u64, u128, usize, i8, i16, i32, i64, i128, isize);
+    into(u64, u128, i64, i128);
+    try_into(u8, u16, u32, usize, i8, i16, i32, isize);
+}
+
+odd_int_impl! {
+    /// A 56-bit signed integer.
+    I56(i64, 56);
+    from(u8, u16, u32, i8, i16, i32);
+    try_from(u64, u128, usize, i64, i128, isize);
+    into(i64, i128);
+    try_into(u8, u16, u32, u64, u128, usize, i8, i16, i32, isize);
+}
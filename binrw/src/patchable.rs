@@ -0,0 +1,226 @@
+use crate::{
+    io::{self, Cursor, Read, Seek, SeekFrom, Write},
+    BinRead, BinResult, BinWrite, Endian, Error,
+};
+#[cfg(not(feature = "std"))]
+use alloc::{format, vec::Vec};
+use core::fmt;
+
+/// A wrapper that stores the position and size of a value alongside the value,
+/// so that a modified value can be written back over the original one.
+///
+/// This allows a single field (or any other value with a fixed-size encoding)
+/// of an existing file to be edited without rewriting the rest of the file.
+/// Serializing a `Patchable` normally will ignore the `pos` and `size` fields.
+///
+/// # Examples
+///
+/// ```
+/// use binrw::{BinRead, Patchable, io::Cursor};
+///
+/// #[derive(BinRead)]
+/// #[br(big)]
+/// struct Header {
+///     magic: u16,
+///     version: Patchable<u32>,
+/// }
+///
+/// let mut file = Cursor::new(b"\xCA\xFE\0\0\0\x01\xAA\xBB".to_vec());
+/// let mut header = Header::read(&mut file).unwrap();
+/// assert_eq!(header.version.pos, 2);
+/// assert_eq!(header.version.size, 4);
+///
+/// *header.version = 2;
+/// header.version.patch_be(&mut file).unwrap();
+/// assert_eq!(file.into_inner(), b"\xCA\xFE\0\0\0\x02\xAA\xBB");
+/// ```
+pub struct Patchable<T> {
+    /// The read value.
+    pub val: T,
+
+    /// The byte position of the start of the value.
+    pub pos: u64,
+
+    /// The number of bytes the value occupied when it was read.
+    pub size: u64,
+}
+
+impl<T: BinWrite> Patchable<T> {
+    /// Writes the value over the original value, assuming big-endian byte
+    /// order.
+    ///
+    /// # Errors
+    ///
+    /// If the size of the new encoding is different from the size of the
+    /// original value, or if writing fails, an [`Error`] variant will be
+    /// returned.
+    pub fn patch_be<W: Write + Seek>(&self, writer: &mut W) -> BinResult<()>
+    where
+        for<'a> T::Args<'a>: crate::__private::Required,
+    {
+        self.patch_options(writer, Endian::Big, crate::__private::Required::args())
+    }
+
+    /// Writes the value over the original value, assuming little-endian byte
+    /// order.
+    ///
+    /// # Errors
+    ///
+    /// If the size of the new encoding is different from the size of the
+    /// original value, or if writing fails, an [`Error`] variant will be
+    /// returned.
+    pub fn patch_le<W: Write + Seek>(&self, writer: &mut W) -> BinResult<()>
+    where
+        for<'a> T::Args<'a>: crate::__private::Required,
+    {
+        self.patch_options(writer, Endian::Little, crate::__private::Required::args())
+    }
+
+    /// Writes the value over the original value using the given byte order and
+    /// arguments.
+    ///
+    /// The value is encoded in memory first, as if it were being written at
+    /// its original position, so the writer is not modified if encoding fails
+    /// or if the size of the new encoding is different from the size of the
+    /// original value. The position of the writer is restored afterwards.
+    ///
+    /// # Errors
+    ///
+    /// If the size of the new encoding is different from the size of the
+    /// original value, or if writing fails, an [`Error`] variant will be
+    /// returned.
+    pub fn patch_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        args: T::Args<'_>,
+    ) -> BinResult<()> {
+        let mut buf = PatchBuffer {
+            base: self.pos,
+            inner: Cursor::new(Vec::new()),
+        };
+        self.val.write_options(&mut buf, endian, args)?;
+        let data = buf.inner.into_inner();
+
+        if data.len() as u64 != self.size {
+            return Err(Error::AssertFail {
+                pos: self.pos,
+                message: format!(
+                    "patched value is {} bytes, but the original value is {} bytes",
+                    data.len(),
+                    self.size
+                ),
+            });
+        }
+
+        let restore = writer.stream_position()?;
+        writer.seek(SeekFrom::Start(self.pos))?;
+        writer.write_all(&data)?;
+        writer.seek(SeekFrom::Start(restore))?;
+        Ok(())
+    }
+}
+
+impl<T: BinRead> BinRead for Patchable<T> {
+    type Args<'a> = T::Args<'a>;
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<Self> {
+        let pos = reader.stream_position()?;
+        let val = T::read_options(reader, endian, args)?;
+        let size = reader.stream_position()? - pos;
+
+        Ok(Patchable { val, pos, size })
+    }
+}
+
+impl<T: BinWrite> BinWrite for Patchable<T> {
+    type Args<'a> = T::Args<'a>;
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<()> {
+        self.val.write_options(writer, endian, args)
+    }
+}
+
+impl<T> core::ops::Deref for Patchable<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.val
+    }
+}
+
+impl<T> core::ops::DerefMut for Patchable<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.val
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Patchable<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.val.fmt(f)
+    }
+}
+
+impl<T: Clone> Clone for Patchable<T> {
+    fn clone(&self) -> Self {
+        Self {
+            val: self.val.clone(),
+            pos: self.pos,
+            size: self.size,
+        }
+    }
+}
+
+impl<U, T: PartialEq<U>> PartialEq<U> for Patchable<T> {
+    fn eq(&self, other: &U) -> bool {
+        self.val == *other
+    }
+}
+
+/// An in-memory writer which reports positions relative to the position of the
+/// value being patched, so that position-dependent encodings like alignment
+/// are the same as they would be in the original stream.
+struct PatchBuffer {
+    base: u64,
+    inner: Cursor<Vec<u8>>,
+}
+
+impl Write for PatchBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.inner.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for PatchBuffer {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        let pos = match pos {
+            SeekFrom::Start(pos) => {
+                SeekFrom::Start(pos.checked_sub(self.base).ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "cannot seek before the start of a patched value",
+                    )
+                })?)
+            }
+            pos => pos,
+        };
+        Ok(self.base + self.inner.seek(pos)?)
+    }
+
+    fn stream_position(&mut self) -> io::Result<u64> {
+        Ok(self.base + self.inner.position())
+    }
+}
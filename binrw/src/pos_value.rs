@@ -2,7 +2,7 @@ use crate::{
     io::{Read, Seek, Write},
     BinRead, BinResult, BinWrite, Endian,
 };
-use core::fmt;
+use core::{fmt, ops::Range};
 
 /// A wrapper that stores a value’s position alongside the value.
 /// Serializing a `PosValue` will ignore the `pos` field.
@@ -112,3 +112,143 @@ impl<T> From<T> for PosValue<T> {
         }
     }
 }
+
+/// A wrapper that stores the start and end positions of a value alongside the
+/// value.
+/// Serializing a `Spanned` will ignore the `start` and `end` fields.
+///
+/// This is like [`PosValue`], but also records the number of bytes the value
+/// occupied, which is useful for measuring variable-length records and for
+/// validating declared lengths.
+///
+/// # Examples
+///
+/// ```
+/// use binrw::{BinRead, Spanned, NullString, BinReaderExt, io::Cursor};
+///
+/// #[derive(BinRead)]
+/// struct Record {
+///     len: u8,
+///     #[br(assert(name.byte_len() == u64::from(len), "bad name length"))]
+///     name: Spanned<NullString>,
+/// }
+///
+/// let val = Cursor::new(b"\x04abc\0").read_be::<Record>().unwrap();
+/// assert_eq!(val.name.start, 1);
+/// assert_eq!(val.name.end, 5);
+/// assert_eq!(val.name.range(), 1..5);
+/// assert_eq!(val.name.byte_len(), 4);
+/// assert_eq!(val.name.len(), 3);
+/// assert_eq!(val.name.to_string(), "abc");
+/// ```
+pub struct Spanned<T> {
+    /// The read value.
+    pub val: T,
+
+    /// The byte position of the start of the value.
+    pub start: u64,
+
+    /// The byte position of the end of the value.
+    pub end: u64,
+}
+
+impl<T> Spanned<T> {
+    /// Returns the number of bytes the value occupied, or zero if `end` is
+    /// before `start`.
+    ///
+    /// This is not called `len` so that it does not shadow the `len` method of
+    /// the wrapped value.
+    pub fn byte_len(&self) -> u64 {
+        self.end.saturating_sub(self.start)
+    }
+
+    /// Returns the range of byte positions the value occupied.
+    pub fn range(&self) -> Range<u64> {
+        self.start..self.end
+    }
+}
+
+impl<T: BinRead> BinRead for Spanned<T> {
+    type Args<'a> = T::Args<'a>;
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<Self> {
+        let start = reader.stream_position()?;
+        let val = T::read_options(reader, endian, args)?;
+        let end = reader.stream_position()?;
+
+        Ok(Spanned { val, start, end })
+    }
+}
+
+impl<T: BinWrite> BinWrite for Spanned<T> {
+    type Args<'a> = T::Args<'a>;
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<()> {
+        self.val.write_options(writer, endian, args)
+    }
+}
+
+impl<T> core::ops::Deref for Spanned<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.val
+    }
+}
+
+impl<T> core::ops::DerefMut for Spanned<T> {
+    fn deref_mut(&mut self) -> &mut T {
+        &mut self.val
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for Spanned<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.val.fmt(f)
+    }
+}
+
+impl<T: Clone> Clone for Spanned<T> {
+    fn clone(&self) -> Self {
+        Self {
+            val: self.val.clone(),
+            start: self.start,
+            end: self.end,
+        }
+    }
+}
+
+impl<U, T: PartialEq<U>> PartialEq<U> for Spanned<T> {
+    fn eq(&self, other: &U) -> bool {
+        self.val == *other
+    }
+}
+
+impl<T: Default> Default for Spanned<T> {
+    fn default() -> Self {
+        Self {
+            val: Default::default(),
+            start: Default::default(),
+            end: Default::default(),
+        }
+    }
+}
+
+impl<T> From<T> for Spanned<T> {
+    fn from(val: T) -> Self {
+        Self {
+            val,
+            start: Default::default(),
+            end: Default::default(),
+        }
+    }
+}
@@ -1,10 +1,10 @@
 use crate::{
     error::{Backtrace, BacktraceFrame, CustomError},
-    io::{Read, Seek, SeekFrom, Write},
+    io::{Read, Seek, SeekFrom, TakeSeek, TakeSeekExt, Write},
     BinRead, BinResult, BinWrite, Endian, Error,
 };
 #[cfg(not(feature = "std"))]
-use alloc::{boxed::Box, string::String};
+use alloc::{boxed::Box, format, string::String};
 
 pub use crate::named_args::{
     builder_helper, passthrough_helper, Needed, Optional, Satisfied, SatisfiedOrOptional,
@@ -101,6 +101,40 @@ where
     }
 }
 
+pub fn read_endian_marker<R: Read + Seek, const N: usize>(
+    reader: &mut R,
+    big: &[u8; N],
+    little: &[u8; N],
+) -> BinResult<Endian> {
+    let pos = reader.stream_position()?;
+    let mut marker = [0; N];
+    reader.read_exact(&mut marker)?;
+    if marker == *big {
+        Ok(Endian::Big)
+    } else if marker == *little {
+        Ok(Endian::Little)
+    } else {
+        Err(Error::BadMagic {
+            pos,
+            found: Box::new(marker) as _,
+        })
+    }
+}
+
+pub fn write_endian_marker<W: Write, const N: usize>(
+    writer: &mut W,
+    endian: Endian,
+    big: &[u8; N],
+    little: &[u8; N],
+) -> BinResult<()> {
+    writer
+        .write_all(match endian {
+            Endian::Big => big,
+            Endian::Little => little,
+        })
+        .map_err(Into::into)
+}
+
 pub fn parse_fn_type_hint<Ret, ParseFn, R, Args>(f: ParseFn) -> ParseFn
 where
     R: Read + Seek,
@@ -204,6 +238,74 @@ pub fn restore_position_variant<S: Seek>(
     }
 }
 
+// The lifetime of the limited reader is tied to the caller’s borrow so that
+// the generated read function, which is declared outside of the closure, can
+// be inferred for it.
+pub fn take_read<'a, R, T, ReadFn>(
+    reader: &'a mut R,
+    len: u64,
+    exact: bool,
+    read: ReadFn,
+) -> BinResult<T>
+where
+    R: Read + Seek,
+    ReadFn: FnOnce(&mut TakeSeek<&'a mut R>) -> BinResult<T>,
+{
+    let pos = reader.stream_position()?;
+    let end = pos.checked_add(len).ok_or_else(|| Error::AssertFail {
+        pos,
+        message: format!("field length {len} overflows the stream position"),
+    })?;
+    let mut inner = reader.take_seek(len);
+    let value = read(&mut inner)?;
+    let remaining = inner.limit();
+    let reader = inner.into_inner();
+
+    if exact && remaining != 0 {
+        return Err(Error::AssertFail {
+            pos,
+            message: format!(
+                "expected field to consume {len} bytes, but it consumed {}",
+                len - remaining
+            ),
+        });
+    }
+
+    if remaining == 0 {
+        reader.seek(SeekFrom::Start(end))?;
+    } else {
+        // Seeking past the end of a stream is not an error, so the last byte is
+        // read to make sure the whole field is there
+        reader.seek(SeekFrom::Start(end - 1))?;
+        reader.read_exact(&mut [0])?;
+    }
+    Ok(value)
+}
+
+pub fn take_write<W, WriteFn>(
+    writer: &mut W,
+    len: u64,
+    exact: bool,
+    write: WriteFn,
+) -> BinResult<()>
+where
+    W: Write + Seek,
+    WriteFn: FnOnce(&mut W) -> BinResult<()>,
+{
+    let pos = writer.stream_position()?;
+    write(writer)?;
+    let size = writer.stream_position()?.saturating_sub(pos);
+
+    if size > len || (exact && size != len) {
+        return Err(Error::AssertFail {
+            pos,
+            message: format!("expected field to write {len} bytes, but it wrote {size}"),
+        });
+    }
+
+    write_zeroes(writer, len - size)
+}
+
 pub fn write_try_map_args_type_hint<Input, Output, Error, MapFn, Args>(
     _: &MapFn,
     args: Args,
@@ -1,8 +1,11 @@
 //! Type definitions for wrappers which parse interleaved data.
 
-use crate::{BinRead, BinResult, VecArgs};
+use crate::{
+    io::{Seek, Write},
+    BinRead, BinResult, BinWrite, Endian, Error, VecArgs,
+};
 #[cfg(not(feature = "std"))]
-use alloc::vec::Vec;
+use alloc::{format, vec, vec::Vec};
 use core::fmt;
 
 /// A parser for data which consists of values of type `T` interleaved with
@@ -18,6 +21,20 @@ use core::fmt;
 /// Consider using a `Vec<(T, P)>` or `(Vec<(T, P)>, Option<T>>)` instead if you
 /// do not need the parsed data to be transformed into a structure of arrays.
 ///
+/// When written, each value is followed by the separator at the same index, if
+/// there is one, so a `Punctuated` which was read with a trailing separator is
+/// also written with one. To check that the number of separators is correct
+/// for the format, select either [`write_separated()`] or
+/// [`write_separated_trailing()`] using [`write_with`]. A `Punctuated` can be
+/// built from a plain `Vec` of values with [`from_values()`] or
+/// [`from_values_trailing()`].
+///
+/// [`write_separated()`]: Self::write_separated
+/// [`write_separated_trailing()`]: Self::write_separated_trailing
+/// [`write_with`]: crate::docs::attribute#custom-parserswriters
+/// [`from_values()`]: Self::from_values
+/// [`from_values_trailing()`]: Self::from_values_trailing
+///
 /// # Examples
 ///
 /// ```
@@ -44,6 +61,54 @@ pub struct Punctuated<T: BinRead, P: BinRead> {
     pub separators: Vec<P>,
 }
 
+impl<T: BinRead, P: BinRead> Punctuated<T, P> {
+    /// Creates a new object from data values and separator values.
+    ///
+    /// Each value is followed by the separator at the same index, so there
+    /// should be either one fewer separator than values, or the same number of
+    /// separators and values if there is a trailing separator.
+    #[must_use]
+    pub fn new(data: Vec<T>, separators: Vec<P>) -> Self {
+        Self { data, separators }
+    }
+
+    /// Creates a new object from data values, with a copy of `separator`
+    /// between each value and no trailing separator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use binrw::{prelude::*, io::Cursor};
+    /// use binrw::punctuated::Punctuated;
+    ///
+    /// let list = Punctuated::<u16, u8>::from_values(vec![3, 2, 1], 0xff);
+    /// assert_eq!(list.separators, vec![0xff, 0xff]);
+    ///
+    /// let mut out = Cursor::new(Vec::new());
+    /// list.write_be(&mut out).unwrap();
+    /// assert_eq!(out.into_inner(), b"\0\x03\xff\0\x02\xff\0\x01");
+    /// ```
+    #[must_use]
+    pub fn from_values(data: Vec<T>, separator: P) -> Self
+    where
+        P: Clone,
+    {
+        let separators = vec![separator; data.len().saturating_sub(1)];
+        Self { data, separators }
+    }
+
+    /// Creates a new object from data values, with a copy of `separator`
+    /// after each value, including a trailing separator.
+    #[must_use]
+    pub fn from_values_trailing(data: Vec<T>, separator: P) -> Self
+    where
+        P: Clone,
+    {
+        let separators = vec![separator; data.len()];
+        Self { data, separators }
+    }
+}
+
 impl<T, P> Punctuated<T, P>
 where
     T: BinRead,
@@ -132,6 +197,118 @@ where
     }
 }
 
+impl<T, P> Punctuated<T, P>
+where
+    T: BinRead + BinWrite,
+    P: BinRead + for<'a> BinWrite<Args<'a> = ()>,
+    for<'a> <T as BinWrite>::Args<'a>: Clone,
+{
+    /// Writes values of type `T` separated by values of type `P` without a
+    /// trailing separator value.
+    ///
+    /// # Errors
+    ///
+    /// If there is not exactly one fewer separator than values, or if writing
+    /// fails, an [`Error`](crate::Error) variant will be returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use binrw::{prelude::*, io::Cursor};
+    /// use binrw::punctuated::Punctuated;
+    ///
+    /// #[binrw]
+    /// #[brw(big)]
+    /// struct MyList {
+    ///     #[br(temp)]
+    ///     #[bw(calc = x.len() as u8)]
+    ///     count: u8,
+    ///     #[br(parse_with = Punctuated::separated, count = count)]
+    ///     #[bw(write_with = Punctuated::write_separated)]
+    ///     x: Punctuated<u16, u8>,
+    /// }
+    ///
+    /// # let mut x = Cursor::new(b"\x03\0\x03\0\0\x02\x01\0\x01");
+    /// # let y: MyList = x.read_be().unwrap();
+    /// # let mut out = Cursor::new(Vec::new());
+    /// # y.write(&mut out).unwrap();
+    /// # assert_eq!(out.into_inner(), x.into_inner());
+    /// ```
+    pub fn write_separated<W: Write + Seek>(
+        value: &Self,
+        writer: &mut W,
+        endian: Endian,
+        args: <T as BinWrite>::Args<'_>,
+    ) -> BinResult<()> {
+        value.check_separators(writer, value.data.len().saturating_sub(1))?;
+        value.write_options(writer, endian, args)
+    }
+
+    /// Writes values of type `T` interleaved with values of type `P`,
+    /// including a trailing `P`.
+    ///
+    /// # Errors
+    ///
+    /// If there is not exactly one separator for each value, or if writing
+    /// fails, an [`Error`](crate::Error) variant will be returned.
+    pub fn write_separated_trailing<W: Write + Seek>(
+        value: &Self,
+        writer: &mut W,
+        endian: Endian,
+        args: <T as BinWrite>::Args<'_>,
+    ) -> BinResult<()> {
+        value.check_separators(writer, value.data.len())?;
+        value.write_options(writer, endian, args)
+    }
+
+    fn check_separators<W: Seek>(&self, writer: &mut W, expected: usize) -> BinResult<()> {
+        if self.separators.len() == expected {
+            Ok(())
+        } else {
+            Err(Error::AssertFail {
+                pos: writer.stream_position()?,
+                message: format!(
+                    "expected {} separators for {} values, but found {}",
+                    expected,
+                    self.data.len(),
+                    self.separators.len()
+                ),
+            })
+        }
+    }
+}
+
+impl<T, P> BinWrite for Punctuated<T, P>
+where
+    T: BinRead + BinWrite,
+    P: BinRead + for<'a> BinWrite<Args<'a> = ()>,
+    for<'a> <T as BinWrite>::Args<'a>: Clone,
+{
+    type Args<'a> = <T as BinWrite>::Args<'a>;
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<()> {
+        let (min, max) = (self.data.len().saturating_sub(1), self.data.len());
+        if self.separators.len() < min || self.separators.len() > max {
+            self.check_separators(writer, min)?;
+        }
+
+        let mut separators = self.separators.iter();
+        for value in &self.data {
+            value.write_options(writer, endian, args.clone())?;
+            if let Some(separator) = separators.next() {
+                separator.write_options(writer, endian, ())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
 impl<T: BinRead + fmt::Debug, P: BinRead> fmt::Debug for Punctuated<T, P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.data.fmt(f)
@@ -2,13 +2,271 @@
 
 use crate::{
     alloc::string::{FromUtf16Error, FromUtf8Error},
-    io::{Read, Seek, Write},
-    BinRead, BinResult, BinWrite, Endian,
+    io::{self, Read, Seek, Write},
+    BinRead, BinResult, BinWrite, Endian, Error, NamedArgs,
 };
 #[cfg(not(feature = "std"))]
 use alloc::{string::String, vec, vec::Vec};
 use core::fmt::{self, Write as _};
 
+/// A text encoding used when reading or writing [`String`], [`str`], and
+/// [`char`] values.
+///
+/// Multi-byte code units (UTF-16 and UTF-32) are read and written using the
+/// endianness of the stream.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Encoding {
+    /// UTF-8. Each `char` is 1–4 bytes wide.
+    #[default]
+    Utf8,
+    /// UTF-16. Each `char` is one or two 16-bit code units wide.
+    Utf16,
+    /// UTF-32. Each `char` is one 32-bit code unit wide.
+    Utf32,
+    /// ISO 8859-1. Each `char` is one byte wide, and only code points up to
+    /// U+00FF can be represented.
+    Latin1,
+}
+
+impl Encoding {
+    /// Returns the size, in bytes, of a single code unit in this encoding.
+    #[must_use]
+    pub fn unit_size(self) -> usize {
+        match self {
+            Self::Utf8 | Self::Latin1 => 1,
+            Self::Utf16 => 2,
+            Self::Utf32 => 4,
+        }
+    }
+}
+
+/// Named arguments for the [`BinRead::read_options()`] implementation of
+/// [`String`].
+///
+/// # Examples
+///
+/// ```
+/// use binrw::{BinRead, Encoding, io::Cursor};
+///
+/// #[derive(BinRead)]
+/// # #[derive(Debug, PartialEq)]
+/// #[br(little)]
+/// struct Record {
+///     len: u8,
+///     #[br(count = len)]
+///     name: String,
+///     #[br(args { count: 4, encoding: Encoding::Utf16 })]
+///     code: String,
+/// }
+///
+/// assert_eq!(
+///     Record::read(&mut Cursor::new(b"\x03abcO\0K\0")).unwrap(),
+///     Record {
+///         len: 3,
+///         name: "abc".into(),
+///         code: "OK".into(),
+///     }
+/// );
+/// ```
+#[derive(NamedArgs, Clone, Copy)]
+pub struct StringArgs {
+    /// The number of bytes to read.
+    pub count: usize,
+
+    /// The encoding of the string.
+    #[named_args(default = Encoding::Utf8)]
+    pub encoding: Encoding,
+}
+
+impl BinRead for String {
+    type Args<'a> = StringArgs;
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<Self> {
+        let pos = reader.stream_position()?;
+
+        if args.count % args.encoding.unit_size() != 0 {
+            return Err(Error::AssertFail {
+                pos,
+                message: alloc::format!(
+                    "byte count {} is not a multiple of the {:?} code unit size",
+                    args.count,
+                    args.encoding
+                ),
+            });
+        }
+
+        let mut bytes = vec![];
+        reader
+            .take(args.count as u64)
+            .read_to_end(&mut bytes)
+            .map_err(Error::Io)?;
+        if bytes.len() != args.count {
+            return Err(Error::Io(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "not enough bytes to read string",
+            )));
+        }
+
+        decode_string(bytes, endian, args.encoding).map_err(|message| Error::AssertFail {
+            pos,
+            message: alloc::format!("invalid {:?} string: {}", args.encoding, message),
+        })
+    }
+}
+
+impl BinWrite for str {
+    type Args<'a> = Encoding;
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<()> {
+        if args == Encoding::Utf8 {
+            writer.write_all(self.as_bytes())?;
+        } else {
+            for c in self.chars() {
+                c.write_options(writer, endian, args)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl BinWrite for String {
+    type Args<'a> = Encoding;
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<()> {
+        self.as_str().write_options(writer, endian, args)
+    }
+}
+
+impl BinRead for char {
+    type Args<'a> = Encoding;
+
+    fn read_options<R: Read + Seek>(
+        reader: &mut R,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<Self> {
+        let pos = reader.stream_position()?;
+
+        let c = match args {
+            Encoding::Utf8 => {
+                let lead = <u8>::read_options(reader, endian, ())?;
+                let len = match lead {
+                    0x00..=0x7F => 1,
+                    0xC0..=0xDF => 2,
+                    0xE0..=0xEF => 3,
+                    0xF0..=0xF7 => 4,
+                    _ => 0,
+                };
+                let mut buf = [lead, 0, 0, 0];
+                if len > 1 {
+                    reader.read_exact(&mut buf[1..len])?;
+                }
+                core::str::from_utf8(&buf[..len])
+                    .ok()
+                    .and_then(|s| s.chars().next())
+            }
+            Encoding::Utf16 => {
+                let lead = <u16>::read_options(reader, endian, ())?;
+                let mut units = [lead, 0];
+                let len = if (0xD800..0xDC00).contains(&lead) {
+                    units[1] = <u16>::read_options(reader, endian, ())?;
+                    2
+                } else {
+                    1
+                };
+                char::decode_utf16(units[..len].iter().copied())
+                    .next()
+                    .and_then(Result::ok)
+            }
+            Encoding::Utf32 => char::from_u32(<u32>::read_options(reader, endian, ())?),
+            Encoding::Latin1 => Some(char::from(<u8>::read_options(reader, endian, ())?)),
+        };
+
+        c.ok_or_else(|| Error::AssertFail {
+            pos,
+            message: alloc::format!("invalid {args:?} character"),
+        })
+    }
+}
+
+impl BinWrite for char {
+    type Args<'a> = Encoding;
+
+    fn write_options<W: Write + Seek>(
+        &self,
+        writer: &mut W,
+        endian: Endian,
+        args: Self::Args<'_>,
+    ) -> BinResult<()> {
+        match args {
+            Encoding::Utf8 => {
+                writer.write_all(self.encode_utf8(&mut [0; 4]).as_bytes())?;
+            }
+            Encoding::Utf16 => {
+                for unit in self.encode_utf16(&mut [0; 2]) {
+                    unit.write_options(writer, endian, ())?;
+                }
+            }
+            Encoding::Utf32 => u32::from(*self).write_options(writer, endian, ())?,
+            Encoding::Latin1 => {
+                let byte = u8::try_from(*self).map_err(|_| Error::AssertFail {
+                    pos: writer.stream_position().unwrap_or_default(),
+                    message: alloc::format!("character {self:?} cannot be encoded as {args:?}"),
+                })?;
+                byte.write_options(writer, endian, ())?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn decode_string(bytes: Vec<u8>, endian: Endian, encoding: Encoding) -> Result<String, String> {
+    match encoding {
+        Encoding::Utf8 => String::from_utf8(bytes).map_err(|err| alloc::format!("{err}")),
+        Encoding::Utf16 => {
+            let units = bytes
+                .chunks_exact(2)
+                .map(|unit| {
+                    let unit = [unit[0], unit[1]];
+                    match endian {
+                        Endian::Big => u16::from_be_bytes(unit),
+                        Endian::Little => u16::from_le_bytes(unit),
+                    }
+                })
+                .collect::<Vec<_>>();
+            String::from_utf16(&units).map_err(|err| alloc::format!("{err}"))
+        }
+        Encoding::Utf32 => bytes
+            .chunks_exact(4)
+            .map(|unit| {
+                let unit = [unit[0], unit[1], unit[2], unit[3]];
+                let unit = match endian {
+                    Endian::Big => u32::from_be_bytes(unit),
+                    Endian::Little => u32::from_le_bytes(unit),
+                };
+                char::from_u32(unit).ok_or_else(|| alloc::format!("invalid code point {unit:#x}"))
+            })
+            .collect(),
+        Encoding::Latin1 => Ok(bytes.into_iter().map(char::from).collect()),
+    }
+}
+
 /// A null-terminated 8-bit string.
 ///
 /// The null terminator is consumed and not included in the value.
@@ -75,6 +75,26 @@ fn clone_args() {
     TestCloneArray::read_le(&mut Cursor::new(b"")).unwrap();
 }
 
+#[test]
+fn cow() {
+    use std::borrow::Cow;
+
+    assert_eq!(
+        Cow::<[u8]>::read_args(&mut Cursor::new(b"\x01\x02\x03"), binrw::args! { count: 2 })
+            .unwrap(),
+        Cow::<[u8]>::Owned(vec![1, 2])
+    );
+    assert_eq!(
+        Cow::<str>::read_options(
+            &mut Cursor::new(b"abc"),
+            binrw::Endian::Little,
+            binrw::args! { count: 3 }
+        )
+        .unwrap(),
+        "abc"
+    );
+}
+
 #[test]
 fn non_zero() {
     assert!(matches!(
@@ -92,6 +112,23 @@ fn phantom_data() {
     core::marker::PhantomData::<()>::read(&mut Cursor::new(b"")).unwrap();
 }
 
+#[test]
+fn rc_arc() {
+    use std::{rc::Rc, sync::Arc};
+
+    assert_eq!(
+        Rc::<u16>::read_be(&mut Cursor::new(b"\0\x03")).unwrap(),
+        Rc::new(3_u16)
+    );
+    assert_eq!(
+        Arc::<u16>::read_le(&mut Cursor::new(b"\x03\0")).unwrap(),
+        Arc::new(3_u16)
+    );
+    assert!(Arc::<u16>::read_le(&mut Cursor::new(b"\x03"))
+        .unwrap_err()
+        .is_eof());
+}
+
 #[test]
 fn tuple() {
     assert_eq!(
@@ -60,6 +60,15 @@ fn clone_args() {
     );
 }
 
+#[test]
+fn cow() {
+    use std::borrow::Cow;
+
+    compare!(Cow::<[u8]>::Borrowed(&[1, 2]), b"\x01\x02");
+    compare!(Cow::<[u8]>::Owned(vec![1, 2]), b"\x01\x02");
+    compare!(Cow::<[u16]>::Borrowed(&[1]), Endian::Big, b"\0\x01");
+}
+
 #[test]
 fn non_zero() {
     compare!(core::num::NonZeroU8::new(1).unwrap(), b"\x01");
@@ -86,6 +95,15 @@ fn phantom_data() {
     compare!(core::marker::PhantomData::<()>, b"");
 }
 
+#[test]
+fn rc_arc() {
+    use std::{rc::Rc, sync::Arc};
+
+    compare!(Rc::new(3_u16), Endian::Big, b"\0\x03");
+    compare!(Arc::new(3_u16), Endian::Little, b"\x03\0");
+    compare!(Rc::<[u8]>::from(&[1, 2][..]), b"\x01\x02");
+}
+
 #[test]
 fn tuple() {
     compare!((1_u8, 2_u8), b"\x01\x02");
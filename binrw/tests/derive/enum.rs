@@ -320,6 +320,205 @@ fn enum_return_unexpected_error() {
     assert!(matches!(error, binrw::Error::NoVariantMatch { .. }));
 }
 
+#[test]
+fn enum_tag() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(import(kind: u8), tag = kind)]
+    enum Body {
+        #[br(tag_value = 1)]
+        A(u8),
+        #[br(tag_value = 2)]
+        B { a: u16 },
+        #[br(tag_value = 3)]
+        C,
+    }
+
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(big)]
+    struct Record {
+        kind: u8,
+        #[br(args(kind))]
+        body: Body,
+    }
+
+    assert_eq!(
+        Record::read(&mut Cursor::new(b"\x01\x02")).unwrap(),
+        Record {
+            kind: 1,
+            body: Body::A(2)
+        }
+    );
+    assert_eq!(
+        Record::read(&mut Cursor::new(b"\x02\0\x03")).unwrap(),
+        Record {
+            kind: 2,
+            body: Body::B { a: 3 }
+        }
+    );
+    assert_eq!(
+        Record::read(&mut Cursor::new(b"\x03")).unwrap(),
+        Record {
+            kind: 3,
+            body: Body::C
+        }
+    );
+
+    let error = Body::read_be_args(&mut Cursor::new(b""), (4,)).expect_err("accepted bad tag");
+    assert!(matches!(
+        error.root_cause(),
+        binrw::Error::NoVariantMatch { .. }
+    ));
+
+    // A failure inside the selected variant is returned directly instead of
+    // being collected with the other variants
+    let mut data = Cursor::new(b"\x02\0");
+    let error = Record::read(&mut data).expect_err("accepted bad data");
+    assert!(error.is_eof());
+    assert_eq!(data.stream_position().unwrap(), 0);
+}
+
+#[test]
+fn enum_repr_discriminant() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(big, repr = u8)]
+    #[repr(u8)]
+    enum Test {
+        A(u8) = 1,
+        B {
+            a: u16,
+        },
+        #[br(assert(c != 0))]
+        C {
+            c: u8,
+        } = 5,
+        D,
+    }
+
+    assert_eq!(
+        Test::read(&mut Cursor::new(b"\x01\x02")).unwrap(),
+        Test::A(2)
+    );
+    assert_eq!(
+        Test::read(&mut Cursor::new(b"\x02\0\x03")).unwrap(),
+        Test::B { a: 3 }
+    );
+    assert_eq!(
+        Test::read(&mut Cursor::new(b"\x05\x04")).unwrap(),
+        Test::C { c: 4 }
+    );
+    assert_eq!(Test::read(&mut Cursor::new(b"\x06")).unwrap(), Test::D);
+
+    let mut data = Cursor::new(b"\x03");
+    let error = Test::read(&mut data).expect_err("accepted bad discriminant");
+    assert!(matches!(
+        error.root_cause(),
+        binrw::Error::NoVariantMatch { .. }
+    ));
+    assert_eq!(data.stream_position().unwrap(), 0);
+
+    let mut data = Cursor::new(b"\x05\0");
+    Test::read(&mut data).expect_err("accepted bad data");
+    assert_eq!(data.stream_position().unwrap(), 0);
+}
+
+#[test]
+fn enum_tag_other() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(import(kind: u8, len: u8), tag = kind)]
+    enum Body {
+        #[br(tag_value = 1)]
+        A(u8),
+        #[br(other)]
+        Unknown {
+            kind: u8,
+            #[br(count = len)]
+            data: Vec<u8>,
+        },
+    }
+
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(big)]
+    struct Record {
+        kind: u8,
+        len: u8,
+        #[br(args(kind, len))]
+        body: Body,
+    }
+
+    assert_eq!(
+        Record::read(&mut Cursor::new(b"\x01\x01\x02"))
+            .unwrap()
+            .body,
+        Body::A(2)
+    );
+    assert_eq!(
+        Record::read(&mut Cursor::new(b"\x07\x03abc")).unwrap().body,
+        Body::Unknown {
+            kind: 7,
+            data: b"abc".to_vec()
+        }
+    );
+}
+
+#[test]
+fn enum_tag_other_unit_variant() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(import(kind: u8, len: u8), tag = kind)]
+    enum Body {
+        #[br(tag_value = 1)]
+        A,
+        #[br(other)]
+        Unknown(u8, #[br(count = len)] Vec<u8>),
+    }
+
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(import(kind: u8), tag = kind)]
+    enum Single {
+        #[br(tag_value = 1)]
+        A,
+        #[br(other)]
+        Unknown(u8),
+    }
+
+    assert_eq!(
+        Body::read_be_args(&mut Cursor::new(b""), (1, 0)).unwrap(),
+        Body::A
+    );
+    assert_eq!(
+        Body::read_be_args(&mut Cursor::new(b"ab"), (7, 2)).unwrap(),
+        Body::Unknown(7, b"ab".to_vec())
+    );
+    assert_eq!(
+        Single::read_be_args(&mut Cursor::new(b""), (1,)).unwrap(),
+        Single::A
+    );
+    assert_eq!(
+        Single::read_be_args(&mut Cursor::new(b""), (7,)).unwrap(),
+        Single::Unknown(7)
+    );
+}
+
+#[test]
+fn enum_magic_other() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(big, import(len: u8))]
+    enum Test {
+        #[br(other)]
+        Unknown(u8, #[br(count = len)] Vec<u8>),
+        #[br(magic = 1u8)]
+        A(u8),
+    }
+
+    assert_eq!(
+        Test::read_args(&mut Cursor::new(b"\x01\x02"), (1,)).unwrap(),
+        Test::A(2)
+    );
+    assert_eq!(
+        Test::read_args(&mut Cursor::new(b"\x03\x04\x05"), (2,)).unwrap(),
+        Test::Unknown(3, vec![4, 5])
+    );
+}
+
 #[test]
 fn mixed_enum() {
     #[derive(BinRead, Debug, Eq, PartialEq)]
@@ -340,3 +539,94 @@ fn mixed_enum() {
     let result = Test::read(&mut Cursor::new(b"\x02\0\x03\0\x04")).unwrap();
     assert_eq!(result, Test::Two { a: 3, b: 4 });
 }
+
+#[test]
+fn magic_dispatch() {
+    #[derive(BinRead, Debug, Eq, PartialEq)]
+    #[br(big)]
+    enum Test {
+        #[br(magic = b"AB")]
+        A(u8),
+        #[br(magic = b"CD")]
+        C { a: u16 },
+        #[br(magic = b"EF")]
+        E,
+    }
+
+    assert_eq!(Test::read(&mut Cursor::new(b"AB\x01")).unwrap(), Test::A(1));
+    assert_eq!(
+        Test::read(&mut Cursor::new(b"CD\0\x02")).unwrap(),
+        Test::C { a: 2 }
+    );
+    assert_eq!(Test::read(&mut Cursor::new(b"EF")).unwrap(), Test::E);
+
+    let mut data = Cursor::new(b"\0XY");
+    data.set_position(1);
+    let error = Test::read(&mut data).expect_err("accepted bad magic");
+    assert_eq!(data.position(), 1);
+    match error {
+        binrw::Error::EnumErrors {
+            pos,
+            variant_errors,
+        } => {
+            assert_eq!(pos, 1);
+            assert_eq!(variant_errors.len(), 3);
+            for (_, error) in variant_errors {
+                match error {
+                    binrw::Error::BadMagic { pos, found } => {
+                        assert_eq!(pos, 1);
+                        assert_eq!(format!("{found:?}"), format!("{:?}", b"XY"));
+                    }
+                    error => panic!("wrong error type: {error:?}"),
+                }
+            }
+        }
+        error => panic!("wrong error type: {error:?}"),
+    }
+
+    // Errors from the selected variant are reported alongside the others
+    let mut data = Cursor::new(b"CD\0");
+    let error = Test::read(&mut data).expect_err("accepted truncated data");
+    assert_eq!(data.position(), 0);
+    match error {
+        binrw::Error::EnumErrors { variant_errors, .. } => {
+            assert!(matches!(
+                variant_errors[0],
+                ("A", binrw::Error::BadMagic { .. })
+            ));
+            assert_eq!(variant_errors[1].0, "C");
+            assert!(matches!(
+                variant_errors[1].1.root_cause(),
+                binrw::Error::Io(..)
+            ));
+            assert!(matches!(
+                variant_errors[2],
+                ("E", binrw::Error::BadMagic { .. })
+            ));
+        }
+        error => panic!("wrong error type: {error:?}"),
+    }
+}
+
+#[test]
+fn magic_dispatch_equal_magics() {
+    // The same magic written in different ways falls back to trying each
+    // variant in turn
+    #[derive(BinRead, Debug, Eq, PartialEq)]
+    #[br(big)]
+    enum Test {
+        #[br(magic = 1u8, assert(a != 0))]
+        A { a: u8 },
+        #[br(magic = 0x01u8)]
+        B(u8),
+        #[br(magic = b'\x02')]
+        C,
+    }
+
+    assert_eq!(
+        Test::read(&mut Cursor::new(b"\x01\x01")).unwrap(),
+        Test::A { a: 1 }
+    );
+    assert_eq!(Test::read(&mut Cursor::new(b"\x01\0")).unwrap(), Test::B(0));
+    assert_eq!(Test::read(&mut Cursor::new(b"\x02")).unwrap(), Test::C);
+}
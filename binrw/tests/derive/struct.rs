@@ -207,6 +207,36 @@ fn deref_now() {
     );
 }
 
+#[test]
+fn endian_marker() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(magic = b"T", endian_marker(big = b"MM", little = b"II"))]
+    struct Test {
+        a: u16,
+        #[br(little)]
+        b: u16,
+        c: u32,
+    }
+
+    assert_eq!(
+        Test::read_le(&mut Cursor::new(b"TMM\0\x01\x02\0\0\0\0\x03")).unwrap(),
+        Test { a: 1, b: 2, c: 3 }
+    );
+    assert_eq!(
+        Test::read_be(&mut Cursor::new(b"TII\x01\0\x02\0\x03\0\0\0")).unwrap(),
+        Test { a: 1, b: 2, c: 3 }
+    );
+
+    let error = Test::read_le(&mut Cursor::new(b"TMI\0\x01\x02\0\0\0\0\x03")).unwrap_err();
+    match error {
+        binrw::Error::BadMagic { pos, found } => {
+            assert_eq!(pos, 1);
+            assert_eq!(format!("{:?}", found), "[77, 73]");
+        }
+        _ => panic!("unexpected error: {error:?}"),
+    }
+}
+
 #[test]
 fn move_args() {
     #[derive(Debug, PartialEq)]
@@ -540,6 +570,82 @@ fn magic_const() {
     assert_eq!(Test::MAGIC, b'a');
 }
 
+#[test]
+fn relative_base() {
+    use binrw::io::TakeSeekExt;
+
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(relative, magic = b"R")]
+    struct Inner {
+        a: u8,
+        #[br(align_before = 4)]
+        b: u8,
+        #[br(seek_before = SeekFrom::Start(7), align_after = 3)]
+        c: u8,
+    }
+
+    #[derive(BinRead, Debug, PartialEq)]
+    struct Outer {
+        pad: u8,
+        inner: Inner,
+        d: u8,
+    }
+
+    let data = b"\xffR\x01\0\0\x02\0\0\x03\0\x04";
+    assert_eq!(
+        Outer::read_le(&mut Cursor::new(data)).unwrap(),
+        Outer {
+            pad: 0xff,
+            inner: Inner { a: 1, b: 2, c: 3 },
+            d: 4,
+        }
+    );
+
+    let mut reader = Cursor::new(data);
+    reader.set_position(1);
+    assert_eq!(
+        Inner::read_le(&mut reader.take_seek(9)).unwrap(),
+        Inner { a: 1, b: 2, c: 3 }
+    );
+}
+
+#[test]
+fn relative_base_expr() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(import(base: u64), base = base)]
+    struct Test {
+        #[br(seek_before = SeekFrom::Start(2))]
+        a: u8,
+        #[br(align_before = 4)]
+        b: u8,
+        #[br(seek_before = SeekFrom::Current(1))]
+        c: u8,
+    }
+
+    assert_eq!(
+        Test::read_le_args(&mut Cursor::new(b"\0\0\0\x01\0\x02\0\x03"), (1,)).unwrap(),
+        Test { a: 1, b: 2, c: 3 }
+    );
+}
+
+#[test]
+fn relative_base_map_stream() {
+    use binrw::io::TakeSeekExt;
+
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(relative, magic = b"magic", map_stream = |reader| reader.take_seek(5))]
+    struct Test {
+        a: u8,
+        #[br(align_before = 4)]
+        b: u8,
+    }
+
+    assert_eq!(
+        Test::read_le(&mut Cursor::new(b"magic\x01\0\0\0\x02")).unwrap(),
+        Test { a: 1, b: 2 }
+    );
+}
+
 #[test]
 fn map_stream() {
     use binrw::io::TakeSeekExt;
@@ -607,6 +713,270 @@ fn map_stream_parse_with_args() {
     );
 }
 
+#[test]
+fn take() {
+    #[derive(BinRead, Debug, PartialEq)]
+    struct Inner {
+        a: u8,
+        #[br(parse_with = binrw::helpers::until_eof)]
+        rest: Vec<u8>,
+    }
+
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(big)]
+    struct Test {
+        len: u8,
+        #[br(take = len)]
+        inner: Inner,
+        #[br(take = 4)]
+        short: u16,
+        #[br(take = 2, count = 1)]
+        counted: Vec<u8>,
+        #[br(take = 2, parse_with = binrw::helpers::until_eof)]
+        parsed: Vec<u8>,
+        after: u8,
+    }
+
+    assert_eq!(
+        Test::read(&mut Cursor::new(
+            b"\x03\x01\x02\x03\0\x04\xff\xff\x05\xff\x06\x07\x08"
+        ))
+        .unwrap(),
+        Test {
+            len: 3,
+            inner: Inner {
+                a: 1,
+                rest: vec![2, 3],
+            },
+            short: 4,
+            counted: vec![5],
+            parsed: vec![6, 7],
+            after: 8,
+        }
+    );
+
+    let error = Test::read(&mut Cursor::new(b"\x01")).expect_err("accepted bad data");
+    assert!(matches!(error.root_cause(), binrw::Error::Io(..)));
+
+    // The outer reader must actually contain the whole field
+    #[allow(dead_code)]
+    #[derive(BinRead, Debug)]
+    #[br(big)]
+    struct Skipped {
+        #[br(take = 4)]
+        a: u8,
+    }
+
+    let error = Skipped::read(&mut Cursor::new(b"\x01\x02")).expect_err("accepted truncated data");
+    assert!(matches!(error.root_cause(), binrw::Error::Io(..)));
+
+    // Lengths from the input which overflow the stream position are rejected
+    #[allow(dead_code)]
+    #[derive(BinRead, Debug)]
+    #[br(big)]
+    struct Huge {
+        len: u64,
+        #[br(take = len)]
+        a: u8,
+    }
+
+    let error = Huge::read(&mut Cursor::new(b"\xff\xff\xff\xff\xff\xff\xff\xff\x01"))
+        .expect_err("accepted overflowing length");
+    assert!(matches!(
+        error.root_cause(),
+        binrw::Error::AssertFail { pos: 8, .. }
+    ));
+}
+
+#[test]
+fn until_byte_len() {
+    use binrw::{
+        binrw,
+        helpers::{encoded_len, until_byte_len, until_byte_len_with},
+        BinWrite, Endian,
+    };
+
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    #[brw(big)]
+    struct Test {
+        #[br(temp)]
+        #[bw(try_calc = u8::try_from(encoded_len(helper, Endian::Big, ())?))]
+        helper_len: u8,
+        #[br(parse_with = until_byte_len(helper_len.into()))]
+        helper: Vec<u16>,
+        #[br(temp)]
+        #[bw(try_calc = u8::try_from(encoded_len(custom, Endian::Big, ())?))]
+        custom_len: u8,
+        #[br(parse_with = until_byte_len_with(custom_len.into(), u8::read_options))]
+        custom: Vec<u8>,
+        after: u8,
+    }
+
+    let data = b"\x04\0\x01\0\x02\x03\x03\x04\x05\x06";
+    let value = Test::read(&mut Cursor::new(data)).unwrap();
+    assert_eq!(
+        value,
+        Test {
+            helper: vec![1, 2],
+            custom: vec![3, 4, 5],
+            after: 6,
+        }
+    );
+
+    let mut out = Cursor::new(Vec::new());
+    value.write(&mut out).unwrap();
+    assert_eq!(out.into_inner(), data);
+
+    // Last element straddles the end of the section
+    let error = Test::read(&mut Cursor::new(b"\x03\0\x01\0\x02")).expect_err("accepted bad data");
+    assert!(matches!(
+        error.root_cause(),
+        binrw::Error::AssertFail { pos: 3, message }
+            if message == "element at 0x3 ends 1 bytes past the end of the 3-byte section"
+    ));
+
+    // Zero-sized elements cannot make progress
+    let error = until_byte_len::<_, (), _, Vec<()>>(1)(&mut Cursor::new(b"\0"), Endian::Big, ())
+        .expect_err("accepted bad data");
+    assert!(matches!(error, binrw::Error::AssertFail { pos: 0, .. }));
+
+    // Backwards seeks are counted once
+    #[derive(BinWrite)]
+    struct Rewrite {
+        a: u32,
+        #[bw(seek_before = SeekFrom::Current(-2))]
+        b: u8,
+    }
+    assert_eq!(
+        encoded_len(&Rewrite { a: 0, b: 0 }, Endian::Big, ()).unwrap(),
+        4
+    );
+}
+
+#[test]
+fn until_seq() {
+    use binrw::helpers::{until_bytes, until_bytes_exclusive, until_seq, until_seq_exclusive};
+
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(big)]
+    struct Test {
+        #[br(parse_with = until_bytes(b"\r\n"))]
+        line: Vec<u8>,
+        #[br(parse_with = until_bytes_exclusive(b"\r\n"))]
+        line_exclusive: Vec<u8>,
+        #[br(parse_with = until_seq([0xffff, 0xffff]))]
+        words: Vec<u16>,
+        #[br(parse_with = until_seq_exclusive([0xffff, 0xffff]))]
+        words_exclusive: Vec<u16>,
+        after: u8,
+    }
+
+    let mut data = Cursor::new(
+        b"a\rb\r\nc\r\r\n\xff\xff\0\x01\xff\xff\xff\xff\xff\xff\xff\0\xff\xff\xff\xff\x02",
+    );
+    assert_eq!(
+        Test::read(&mut data).unwrap(),
+        Test {
+            line: b"a\rb\r\n".to_vec(),
+            line_exclusive: b"c\r".to_vec(),
+            words: vec![0xffff, 1, 0xffff, 0xffff],
+            words_exclusive: vec![0xffff, 0xff00],
+            after: 2,
+        }
+    );
+
+    // Terminator spanning the boundary between scanned blocks, with the
+    // overread bytes given back to the reader
+    let mut input = vec![b'x'; 255];
+    input.extend(b"\r\nrest");
+    let mut data = Cursor::new(&input);
+    let line: Vec<u8> = until_bytes_exclusive(b"\r\n")(&mut data, binrw::Endian::Big, ()).unwrap();
+    assert_eq!(line, &input[..255]);
+    assert_eq!(data.stream_position().unwrap(), 257);
+    let rest: Vec<u8> = until_bytes(b"st")(&mut data, binrw::Endian::Big, ()).unwrap();
+    assert_eq!(rest, b"rest");
+
+    let error = until_bytes::<_, _, Vec<u8>>(b"\r\n")(
+        &mut Cursor::new(b"no terminator\r"),
+        binrw::Endian::Big,
+        (),
+    )
+    .expect_err("accepted unterminated data");
+    assert!(matches!(error, binrw::Error::Io(..)));
+
+    let error = until_seq::<_, u16, _, _, Vec<u16>>([0u16, 0])(
+        &mut Cursor::new(b"\0\0\x01\0\0"),
+        binrw::Endian::Big,
+        (),
+    )
+    .expect_err("accepted unterminated data");
+    assert!(matches!(error, binrw::Error::Io(..)));
+
+    // Interrupted reads are retried instead of ending the input
+    struct Interrupting<T>(T, bool);
+    impl<T: binrw::io::Read> binrw::io::Read for Interrupting<T> {
+        fn read(&mut self, buf: &mut [u8]) -> binrw::io::Result<usize> {
+            self.1 = !self.1;
+            if self.1 {
+                Err(binrw::io::ErrorKind::Interrupted.into())
+            } else {
+                self.0.read(buf)
+            }
+        }
+    }
+    impl<T: binrw::io::Seek> binrw::io::Seek for Interrupting<T> {
+        fn seek(&mut self, pos: binrw::io::SeekFrom) -> binrw::io::Result<u64> {
+            self.0.seek(pos)
+        }
+    }
+
+    let line: Vec<u8> = until_bytes(b"\r\n")(
+        &mut Interrupting(Cursor::new(b"line\r\n"), false),
+        binrw::Endian::Big,
+        (),
+    )
+    .unwrap();
+    assert_eq!(line, b"line\r\n");
+}
+
+#[test]
+fn take_exact() {
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(big)]
+    struct Test {
+        #[br(take_exact = 4)]
+        value: u16,
+    }
+
+    let mut data = Cursor::new(b"\0\x01\0\0");
+    let error = Test::read(&mut data).expect_err("accepted short field");
+    assert_eq!(data.position(), 0);
+    match error.root_cause() {
+        binrw::Error::AssertFail { pos, message } => {
+            assert_eq!(*pos, 0);
+            assert_eq!(
+                message,
+                "expected field to consume 4 bytes, but it consumed 2"
+            );
+        }
+        error => panic!("wrong error type: {error:?}"),
+    }
+
+    #[derive(BinRead, Debug, PartialEq)]
+    #[br(big)]
+    struct Exact {
+        #[br(take_exact = 2)]
+        value: u16,
+        after: u8,
+    }
+
+    assert_eq!(
+        Exact::read(&mut Cursor::new(b"\0\x01\x02")).unwrap(),
+        Exact { value: 1, after: 2 }
+    );
+}
+
 #[test]
 fn named_args_trailing_commas() {
     #[rustfmt::skip]
@@ -188,6 +188,82 @@ fn unit_enum_repr() {
     assert_eq!(Test::read(&mut Cursor::new(b"\0\x02")).unwrap(), Test::Two);
 }
 
+#[test]
+fn unit_enum_repr_other() {
+    #[derive(BinRead, Debug, Eq, PartialEq)]
+    #[br(big, repr(u16))]
+    #[repr(u16)]
+    enum Test {
+        Zero,
+        Two = 2,
+        #[br(other)]
+        Unknown(u16),
+    }
+
+    assert_eq!(Test::read(&mut Cursor::new(b"\0\0")).unwrap(), Test::Zero);
+    assert_eq!(Test::read(&mut Cursor::new(b"\0\x02")).unwrap(), Test::Two);
+    assert_eq!(
+        Test::read(&mut Cursor::new(b"\x12\x34")).unwrap(),
+        Test::Unknown(0x1234)
+    );
+}
+
+#[test]
+fn unit_enum_repr_other_pre_assert() {
+    #[derive(BinRead, Debug, Eq, PartialEq)]
+    #[br(repr(u8), import(strict: bool))]
+    #[repr(u8)]
+    enum Test {
+        One = 1,
+        #[br(other, pre_assert(!strict))]
+        Unknown(u8),
+    }
+
+    assert_eq!(
+        Test::read_args(&mut Cursor::new(b"\x07"), (false,)).unwrap(),
+        Test::Unknown(7)
+    );
+    let error = Test::read_args(&mut Cursor::new(b"\x07"), (true,)).expect_err("accepted bad data");
+    assert!(matches!(
+        error.root_cause(),
+        binrw::Error::NoVariantMatch { .. }
+    ));
+    assert_eq!(
+        Test::read_args(&mut Cursor::new(b"\x01"), (true,)).unwrap(),
+        Test::One
+    );
+}
+
+#[test]
+fn unit_enum_repr_pattern() {
+    #[derive(BinRead, Debug, Eq, PartialEq)]
+    #[br(repr(u8))]
+    #[repr(u8)]
+    enum Test {
+        Nop,
+        #[br(magic_range = 0x80..=0x8f)]
+        Vendor(u8),
+        #[br(pattern = 0x10 | 0x20 | 0x30)]
+        Group(u8),
+        #[br(other)]
+        Unknown(u8),
+    }
+
+    assert_eq!(Test::read(&mut Cursor::new(b"\0")).unwrap(), Test::Nop);
+    assert_eq!(
+        Test::read(&mut Cursor::new(b"\x85")).unwrap(),
+        Test::Vendor(0x85)
+    );
+    assert_eq!(
+        Test::read(&mut Cursor::new(b"\x20")).unwrap(),
+        Test::Group(0x20)
+    );
+    assert_eq!(
+        Test::read(&mut Cursor::new(b"\x90")).unwrap(),
+        Test::Unknown(0x90)
+    );
+}
+
 #[test]
 fn unit_enum_rewind_on_eof() {
     #[derive(BinRead, Debug)]
@@ -23,6 +23,28 @@ fn write_enum() {
     );
 }
 
+#[test]
+fn write_enum_other() {
+    #[derive(BinWrite)]
+    #[bw(repr(u16))]
+    #[repr(u16)]
+    enum Test {
+        A,
+        B = 3,
+        C,
+        #[bw(other)]
+        Unknown(u16),
+    }
+
+    let mut x = Cursor::new(Vec::new());
+
+    vec![Test::A, Test::B, Test::C, Test::Unknown(0x1234)]
+        .write_options(&mut x, Endian::Big, ())
+        .unwrap();
+
+    assert_eq!(x.into_inner(), [0, 0, 0, 3, 0, 4, 0x12, 0x34]);
+}
+
 #[test]
 fn round_trip_unit_enum() {
     #[derive(BinRead, BinWrite)]
@@ -72,3 +94,62 @@ fn magic_enum_round_trip() {
 
     assert_eq!(x.into_inner(), data);
 }
+
+#[test]
+fn round_trip_unit_enum_other() {
+    #[derive(BinRead, BinWrite, Debug, PartialEq)]
+    #[brw(little, repr(u8))]
+    #[repr(u8)]
+    enum Test {
+        A = 1,
+        B,
+        #[brw(other)]
+        Unknown(u8),
+    }
+
+    let data = [1, 2, 0x7f];
+    let test: [Test; 3] = Cursor::new(data).read_le().unwrap();
+    assert_eq!(test, [Test::A, Test::B, Test::Unknown(0x7f)]);
+
+    let mut x = Cursor::new(Vec::new());
+    test.write_le(&mut x).unwrap();
+    assert_eq!(x.into_inner(), data);
+}
+
+#[test]
+fn round_trip_unit_enum_pattern() {
+    #[derive(BinRead, BinWrite, Debug, PartialEq)]
+    #[brw(big, repr(u16))]
+    #[repr(u16)]
+    enum Test {
+        A = 1,
+        #[brw(magic_range = 0x8000..=0x80ff)]
+        Vendor(u16),
+        #[brw(pattern = 2 | 4)]
+        Even(u16),
+    }
+
+    let data = [0, 1, 0x80, 0x12, 0, 4];
+    let test: [Test; 3] = Cursor::new(data).read_be().unwrap();
+    assert_eq!(test, [Test::A, Test::Vendor(0x8012), Test::Even(4)]);
+
+    let mut x = Cursor::new(Vec::new());
+    test.write_be(&mut x).unwrap();
+    assert_eq!(x.into_inner(), data);
+
+    Test::read_be(&mut Cursor::new([0, 3])).expect_err("accepted bad data");
+
+    // Values which would not read back as the same variant are rejected
+    let mut x = Cursor::new(Vec::new());
+    let error = [Test::A, Test::Vendor(0x9000)]
+        .write_be(&mut x)
+        .expect_err("accepted bad data");
+    assert!(matches!(
+        error,
+        binrw::Error::AssertFail { pos: 2, message }
+            if message == "value 36864 does not match the pattern of variant `Vendor`"
+    ));
+    Test::Even(3)
+        .write_be(&mut Cursor::new(Vec::new()))
+        .expect_err("accepted bad data");
+}
@@ -38,3 +38,150 @@ fn write_with_fn_once_closure_args() {
     Test { a: 0 }.write(&mut x).unwrap();
     assert_eq!(x.into_inner(), b"\x01");
 }
+
+#[test]
+fn until_helpers() {
+    use binrw::{
+        binrw,
+        helpers::{
+            until, until_eof, until_exclusive, write_until, write_until_eof, write_until_exclusive,
+            write_until_exclusive_with,
+        },
+        BinRead,
+    };
+
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    #[brw(big)]
+    struct Test {
+        #[br(parse_with = until(|&x| x == 0))]
+        #[bw(write_with = write_until(|&x| x == 0))]
+        inclusive: Vec<u16>,
+        #[br(parse_with = until_exclusive(|&x| x == 0xff))]
+        #[bw(write_with = write_until_exclusive(|&x| x == 0xff, 0xff))]
+        exclusive: Vec<u8>,
+        #[br(parse_with = until_eof)]
+        #[bw(write_with = write_until_eof)]
+        rest: Vec<u8>,
+    }
+
+    let data = b"\0\x01\0\x02\0\0\x03\x04\xff\x05\x06";
+    let value = Test::read(&mut Cursor::new(data)).unwrap();
+    assert_eq!(
+        value,
+        Test {
+            inclusive: vec![1, 2, 0],
+            exclusive: vec![3, 4],
+            rest: vec![5, 6],
+        }
+    );
+
+    let mut x = Cursor::new(Vec::new());
+    value.write(&mut x).unwrap();
+    assert_eq!(x.into_inner(), data);
+
+    // Lists which would not read back the same way are rejected
+    let bad_values = [
+        (vec![1, 2], vec![]),
+        (vec![1, 0, 2], vec![]),
+        (vec![], vec![]),
+        (vec![0], vec![3, 0xff, 4]),
+    ];
+    for (inclusive, exclusive) in bad_values {
+        let error = Test {
+            inclusive,
+            exclusive,
+            rest: vec![],
+        }
+        .write(&mut Cursor::new(Vec::new()))
+        .expect_err("accepted bad data");
+        assert!(matches!(
+            error.root_cause(),
+            binrw::Error::AssertFail { .. }
+        ));
+    }
+
+    // Custom item writer
+    let mut x = Cursor::new(Vec::new());
+    let write = write_until_exclusive_with(
+        |&value| value == 0,
+        0_u8,
+        |&value, writer, endian, ()| u16::from(value).write_options(writer, endian, ()),
+    );
+    write(&[1, 2][..], &mut x, Endian::Little, ()).unwrap();
+    assert_eq!(x.into_inner(), b"\x01\0\x02\0\0\0");
+
+    // A terminal item which would not end the list when read is rejected
+    let write = write_until_exclusive_with(
+        |&value| value == 0,
+        1_u8,
+        |&value, writer, endian, ()| value.write_options(writer, endian, ()),
+    );
+    let error = write(&[2][..], &mut Cursor::new(Vec::new()), Endian::Little, ())
+        .expect_err("accepted bad terminator");
+    assert!(matches!(error, binrw::Error::AssertFail { pos: 0, .. }));
+}
+
+#[test]
+fn args_iter_helpers() {
+    use binrw::{
+        helpers::{write_args_iter, write_args_iter_with},
+        BinResult,
+    };
+
+    #[derive(BinWrite)]
+    #[bw(import_raw(scale: &u8))]
+    struct Scaled(#[bw(map = |x| x * scale)] u8);
+
+    #[derive(BinWrite)]
+    struct Test {
+        scales: Vec<u8>,
+        #[bw(write_with = write_args_iter(scales))]
+        values: Vec<Scaled>,
+    }
+
+    let mut x = Cursor::new(Vec::new());
+    Test {
+        scales: vec![2, 3],
+        values: vec![Scaled(5), Scaled(7)],
+    }
+    .write_le(&mut x)
+    .unwrap();
+    assert_eq!(x.into_inner(), b"\x02\x03\x0a\x15");
+
+    for (scales, values, message) in [
+        (
+            vec![2],
+            vec![Scaled(5), Scaled(7)],
+            "found 2 items, but 1 arguments",
+        ),
+        (
+            vec![2, 3, 4],
+            vec![Scaled(5)],
+            "found 1 items, but 3 arguments",
+        ),
+    ] {
+        let error = Test { scales, values }
+            .write_le(&mut Cursor::new(Vec::new()))
+            .expect_err("accepted bad data");
+        assert!(matches!(
+            error.root_cause(),
+            binrw::Error::AssertFail { message: m, .. } if m == message
+        ));
+    }
+
+    fn write_sized(
+        value: &Vec<u8>,
+        writer: &mut Cursor<Vec<u8>>,
+        endian: Endian,
+        size: usize,
+    ) -> BinResult<()> {
+        assert_eq!(value.len(), size);
+        value.write_options(writer, endian, ())
+    }
+
+    let mut x = Cursor::new(Vec::new());
+    write_args_iter_with([1, 2], write_sized)(&vec![vec![1], vec![2, 3]], &mut x, Endian::Big, ())
+        .unwrap();
+    assert_eq!(x.into_inner(), b"\x01\x02\x03");
+}
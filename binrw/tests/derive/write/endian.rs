@@ -65,3 +65,22 @@ fn top_level_endian() {
 
     assert_eq!(x.into_inner(), [1, 0, 2, 0, 0, 0, 0, 3, 0, 0, 0, 4]);
 }
+
+#[test]
+fn endian_marker() {
+    #[derive(BinWrite)]
+    #[bw(magic = b"T", endian_marker(little = b"\xFF\xFE", big = b"\xFE\xFF"))]
+    struct Test {
+        x: u16,
+        #[bw(big)]
+        y: u16,
+    }
+
+    let mut x = Cursor::new(Vec::new());
+    Test { x: 1, y: 2 }.write_le(&mut x).unwrap();
+    assert_eq!(x.into_inner(), b"T\xFF\xFE\x01\0\0\x02");
+
+    let mut x = Cursor::new(Vec::new());
+    Test { x: 1, y: 2 }.write_be(&mut x).unwrap();
+    assert_eq!(x.into_inner(), b"T\xFE\xFF\0\x01\0\x02");
+}
@@ -1,4 +1,4 @@
-use binrw::{io::Cursor, BinRead, BinReaderExt, BinWrite, Endian};
+use binrw::{binrw, io::Cursor, meta::WriteTag, BinRead, BinReaderExt, BinWrite, Endian};
 
 #[test]
 fn enum_round_trip() {
@@ -71,3 +71,145 @@ fn enum_one_way() {
         b"BBB\xBB\xAA\0\0\0\x02CCCAAA\x03\x02\x01\0\xFF"
     );
 }
+
+#[test]
+fn enum_tag() {
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    #[br(import(kind: u8), tag = kind)]
+    #[bw(tag_type = u8)]
+    enum Body {
+        #[brw(tag_value = 1)]
+        A(u8),
+        #[brw(tag_value = 2)]
+        B { a: u16 },
+        #[brw(tag_value = 3)]
+        C,
+    }
+
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    #[brw(big)]
+    struct Record {
+        #[br(temp)]
+        #[bw(calc = body.tag())]
+        kind: u8,
+        #[br(args(kind))]
+        body: Body,
+    }
+
+    assert_eq!(Body::A(0).tag(), 1);
+    assert_eq!(Body::B { a: 0 }.tag(), 2);
+    assert_eq!(Body::C.tag(), 3);
+
+    for data in [&b"\x01\x02"[..], b"\x02\0\x03", b"\x03"] {
+        let record = Record::read(&mut Cursor::new(data)).unwrap();
+        let mut out = Cursor::new(Vec::new());
+        record.write(&mut out).unwrap();
+        assert_eq!(out.into_inner(), data);
+    }
+}
+
+#[test]
+fn enum_repr_discriminant() {
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    #[brw(little, repr = i16)]
+    #[repr(i16)]
+    enum Test {
+        A(u8) = -2,
+        B {
+            a: u16,
+        },
+        #[brw(magic = b'C')]
+        C = 4,
+        D(
+            #[bw(map = |x| x + 1)]
+            #[br(map = |x: u8| x - 1)]
+            u8,
+        ),
+    }
+
+    for (value, data) in [
+        (Test::A(1), &b"\xfe\xff\x01"[..]),
+        (Test::B { a: 2 }, b"\xff\xff\x02\0"),
+        (Test::C, b"\x04\0C"),
+        (Test::D(3), b"\x05\0\x04"),
+    ] {
+        let mut out = Cursor::new(Vec::new());
+        value.write(&mut out).unwrap();
+        assert_eq!(out.into_inner(), data);
+        assert_eq!(Test::read(&mut Cursor::new(data)).unwrap(), value);
+    }
+}
+
+#[test]
+fn enum_tag_other() {
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    #[br(import(kind: u8, len: u16), tag = kind)]
+    #[bw(tag_type = u8)]
+    enum Body {
+        #[brw(tag_value = 1)]
+        A(u8),
+        #[brw(other)]
+        Unknown(u8, #[br(count = len)] Vec<u8>),
+    }
+
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    #[brw(big)]
+    struct Record {
+        #[br(temp)]
+        #[bw(calc = body.tag())]
+        kind: u8,
+        len: u16,
+        #[br(args(kind, len))]
+        body: Body,
+    }
+
+    assert_eq!(Body::Unknown(9, Vec::new()).tag(), 9);
+
+    for data in [&b"\x01\0\x01\x02"[..], b"\x09\0\x03abc"] {
+        let record = Record::read(&mut Cursor::new(data)).unwrap();
+        let mut out = Cursor::new(Vec::new());
+        record.write(&mut out).unwrap();
+        assert_eq!(out.into_inner(), data);
+    }
+}
+
+#[test]
+fn enum_repr_other() {
+    #[binrw]
+    #[derive(Debug, PartialEq)]
+    #[brw(big, repr = u8)]
+    #[br(import(len: u8))]
+    #[repr(u8)]
+    enum Test {
+        A(u16) = 1,
+        #[brw(other)]
+        Unknown {
+            tag: u8,
+            #[br(count = len)]
+            data: Vec<u8>,
+        },
+    }
+
+    let data = b"\x05abc";
+    let value = Test::read_args(&mut Cursor::new(data), (3,)).unwrap();
+    assert_eq!(
+        value,
+        Test::Unknown {
+            tag: 5,
+            data: b"abc".to_vec()
+        }
+    );
+
+    let mut out = Cursor::new(Vec::new());
+    value.write(&mut out).unwrap();
+    assert_eq!(out.into_inner(), data);
+
+    let mut out = Cursor::new(Vec::new());
+    Test::A(2).write(&mut out).unwrap();
+    assert_eq!(out.into_inner(), b"\x01\0\x02");
+}
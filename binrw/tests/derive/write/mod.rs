@@ -17,4 +17,5 @@ mod restore_position;
 mod simple;
 mod stream;
 mod struct_generic;
+mod take;
 mod top_level_map;
@@ -1,4 +1,7 @@
-use binrw::{io::Cursor, BinRead, BinReaderExt, BinWrite, Endian};
+use binrw::{
+    io::{Cursor, SeekFrom},
+    BinRead, BinReaderExt, BinWrite, Endian,
+};
 
 #[test]
 fn padding_big() {
@@ -73,3 +76,68 @@ fn padding_one_way() {
 
     assert_eq!(x.into_inner(), data);
 }
+
+#[test]
+fn padding_relative_round_trip() {
+    #[derive(BinRead, BinWrite, Debug, PartialEq)]
+    #[brw(relative, magic = b"R")]
+    struct Inner {
+        a: u8,
+        #[brw(align_before = 4)]
+        b: u8,
+        #[brw(seek_before = SeekFrom::Start(7), align_after = 3)]
+        c: u8,
+    }
+
+    #[derive(BinRead, BinWrite, Debug, PartialEq)]
+    struct Outer {
+        pad: u8,
+        inner: Inner,
+        d: u8,
+    }
+
+    let data = b"\xffR\x01\0\0\x02\0\0\x03\0\x04";
+    let test: Outer = Cursor::new(data).read_le().unwrap();
+
+    let mut x = Cursor::new(Vec::new());
+    test.write_le(&mut x).unwrap();
+
+    assert_eq!(x.into_inner(), data);
+}
+
+#[test]
+fn padding_base_expr() {
+    #[derive(BinWrite)]
+    #[bw(import(base: u64), base = base)]
+    struct Test {
+        #[bw(seek_before = SeekFrom::Start(2))]
+        a: u8,
+        #[bw(align_before = 4)]
+        b: u8,
+    }
+
+    let mut x = Cursor::new(Vec::new());
+    Test { a: 1, b: 2 }.write_le_args(&mut x, (1,)).unwrap();
+
+    assert_eq!(x.into_inner(), b"\0\0\0\x01\0\x02");
+}
+
+#[test]
+fn padding_base_after_position() {
+    #[derive(BinRead, BinWrite, Debug, PartialEq)]
+    #[brw(import(base: u64), base = base)]
+    struct Test {
+        a: u8,
+        #[brw(align_before = 3)]
+        b: u8,
+    }
+
+    let data = b"\x01\0\x02";
+    let test = Test::read_le_args(&mut Cursor::new(data), (5,)).unwrap();
+    assert_eq!(test, Test { a: 1, b: 2 });
+
+    let mut x = Cursor::new(Vec::new());
+    test.write_le_args(&mut x, (5,)).unwrap();
+
+    assert_eq!(x.into_inner(), data);
+}
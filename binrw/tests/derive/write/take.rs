@@ -0,0 +1,60 @@
+use binrw::{io::Cursor, BinWrite};
+
+#[test]
+fn take() {
+    #[derive(BinWrite)]
+    #[bw(big)]
+    struct Test {
+        len: u8,
+        #[bw(take = *len)]
+        data: Vec<u8>,
+        after: u8,
+    }
+
+    let mut x = Cursor::new(Vec::new());
+    Test {
+        len: 4,
+        data: vec![1, 2],
+        after: 3,
+    }
+    .write(&mut x)
+    .unwrap();
+    assert_eq!(x.into_inner(), b"\x04\x01\x02\0\0\x03");
+
+    let error = Test {
+        len: 1,
+        data: vec![1, 2],
+        after: 3,
+    }
+    .write(&mut Cursor::new(Vec::new()))
+    .expect_err("accepted oversized field");
+    match error.root_cause() {
+        binrw::Error::AssertFail { pos, message } => {
+            assert_eq!(*pos, 1);
+            assert_eq!(message, "expected field to write 1 bytes, but it wrote 2");
+        }
+        error => panic!("wrong error type: {error:?}"),
+    }
+}
+
+#[test]
+fn take_exact() {
+    #[derive(BinWrite)]
+    #[bw(big)]
+    struct Test {
+        #[bw(take_exact = 2)]
+        data: Vec<u8>,
+    }
+
+    let mut x = Cursor::new(Vec::new());
+    Test { data: vec![1, 2] }.write(&mut x).unwrap();
+    assert_eq!(x.into_inner(), b"\x01\x02");
+
+    let error = Test { data: vec![1] }
+        .write(&mut Cursor::new(Vec::new()))
+        .expect_err("accepted undersized field");
+    assert!(matches!(
+        error.root_cause(),
+        binrw::Error::AssertFail { .. }
+    ));
+}
@@ -1,4 +1,4 @@
-use binrw::Endian;
+use binrw::{endian::WordSwapped, io::Cursor, BinRead, BinWrite, Endian};
 
 #[test]
 fn endian_bom() {
@@ -20,3 +20,46 @@ fn endian_to_string() {
     assert_eq!(Endian::Big.to_string(), "Big");
     assert_eq!(Endian::Little.to_string(), "Little");
 }
+
+#[test]
+fn word_swapped() {
+    #[derive(BinRead, BinWrite, Debug, PartialEq)]
+    #[brw(little)]
+    struct Test {
+        pdp: WordSwapped<u32, 2>,
+        words: WordSwapped<u64, 4>,
+        #[brw(big)]
+        honeywell: WordSwapped<i32, 2>,
+        float: WordSwapped<f64, 4>,
+    }
+
+    let data = b"\x0B\x0A\x0D\x0C\x04\x03\x02\x01\x08\x07\x06\x05\x0C\x0D\x0A\x0B\x00\x00\xF0\x3F\x00\x00\x00\x00";
+    let value = Test::read(&mut Cursor::new(data)).unwrap();
+    assert_eq!(
+        value,
+        Test {
+            pdp: WordSwapped(0x0A0B_0C0D),
+            words: WordSwapped(0x0102_0304_0506_0708),
+            honeywell: WordSwapped(0x0A0B_0C0D),
+            float: WordSwapped(1.0),
+        }
+    );
+
+    let mut out = Cursor::new(Vec::new());
+    value.write(&mut out).unwrap();
+    assert_eq!(out.into_inner(), data);
+
+    // A word size equal to the size of the value is the same as the byte order
+    // of the value
+    let mut out = Cursor::new(Vec::new());
+    WordSwapped::<u16, 2>(0x0102).write_be(&mut out).unwrap();
+    WordSwapped::<u16, 1>(0x0102).write_be(&mut out).unwrap();
+    assert_eq!(out.into_inner(), b"\x01\x02\x02\x01");
+}
+
+#[test]
+fn word_swapped_eof() {
+    let mut data = Cursor::new(b"\0\0\0");
+    WordSwapped::<u32, 2>::read_le(&mut data).unwrap_err();
+    assert_eq!(data.position(), 0);
+}
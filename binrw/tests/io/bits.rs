@@ -0,0 +1,158 @@
+use binrw::{
+    binrw,
+    helpers::{read_packed, write_packed},
+    io::{BitOrder, BitReader, BitWriter, Cursor, Read, Seek, SeekFrom, Write},
+    BinRead, BinWrite,
+};
+
+#[test]
+fn bit_reader() {
+    let mut reader = BitReader::new(Cursor::new(b"\xbc\xfa\xde\x81"), BitOrder::LsbFirst);
+    assert_eq!(reader.order(), BitOrder::LsbFirst);
+    assert_eq!(reader.read_bits(12).unwrap(), 0xabc);
+    assert!(!reader.is_aligned());
+    assert_eq!(reader.stream_position().unwrap(), 2);
+    assert_eq!(reader.read_bits(12).unwrap(), 0xdef);
+    assert!(reader.is_aligned());
+    assert!(reader.read_bit().unwrap());
+    assert_eq!(reader.read_bits(0).unwrap(), 0);
+    reader.align();
+    assert!(reader.is_aligned());
+    reader.read_bits(1).unwrap_err();
+    reader.read_bits(65).unwrap_err();
+
+    let mut reader = BitReader::new(Cursor::new(b"\xab\xcd\xef\x12"), BitOrder::MsbFirst);
+    assert_eq!(reader.read_bits(4).unwrap(), 0xa);
+
+    // Byte reads are shifted by the number of bits already read
+    let mut buf = [0; 4];
+    assert_eq!(reader.read(&mut buf).unwrap(), 3);
+    assert_eq!(&buf[..3], b"\xbc\xde\xf1");
+    assert_eq!(reader.read_bits(4).unwrap(), 0x2);
+    assert_eq!(reader.read(&mut buf).unwrap(), 0);
+
+    // Seeking discards the unread bits
+    reader.seek(SeekFrom::Start(0)).unwrap();
+    assert_eq!(reader.read_bits(32).unwrap(), 0xabcdef12);
+    reader.seek(SeekFrom::Start(1)).unwrap();
+    assert_eq!(reader.read_bits(16).unwrap(), 0xcdef);
+    assert_eq!(reader.into_inner().position(), 3);
+}
+
+#[test]
+fn bit_writer() {
+    let mut writer = BitWriter::new(Cursor::new(Vec::new()), BitOrder::LsbFirst);
+    assert_eq!(writer.order(), BitOrder::LsbFirst);
+    writer.write_bits(0xabc, 12).unwrap();
+    assert!(!writer.is_aligned());
+    assert_eq!(writer.stream_position().unwrap(), 2);
+    writer.write_bits(0xdef, 12).unwrap();
+    assert!(writer.is_aligned());
+    writer.write_bit(true).unwrap();
+    writer.write_bits(0, 65).unwrap_err();
+    writer.align().unwrap();
+    assert_eq!(writer.get_ref().get_ref(), b"\xbc\xfa\xde\x01");
+
+    let mut writer = BitWriter::new(Cursor::new(Vec::new()), BitOrder::MsbFirst);
+    writer.write_bits(0xa, 4).unwrap();
+
+    // Byte writes are shifted by the number of bits already written
+    writer.write_all(b"\xbc\xde\xf1").unwrap();
+    writer.write_bits(0x2, 4).unwrap();
+    assert_eq!(writer.get_ref().get_ref(), b"\xab\xcd\xef\x12");
+
+    // Seeking pads and writes the partially filled byte
+    writer.write_bits(0b11, 2).unwrap();
+    writer.seek(SeekFrom::Start(1)).unwrap();
+    writer.write_bits(0xfff, 12).unwrap();
+    assert_eq!(writer.get_ref().get_ref(), b"\xab\xff\xef\x12\xc0");
+
+    // Unused high bits are ignored
+    writer.seek(SeekFrom::End(0)).unwrap();
+    writer.write_bits(0xff0f, 8).unwrap();
+    writer.write_bits(u64::MAX, 64).unwrap();
+    let out = writer.into_inner().unwrap().into_inner();
+    assert_eq!(
+        out,
+        b"\xab\xff\xf0\x12\xc0\x0f\xff\xff\xff\xff\xff\xff\xff\xff"
+    );
+}
+
+#[test]
+fn bit_writer_drop() {
+    let mut out = Cursor::new(Vec::new());
+    {
+        let mut writer = BitWriter::new(&mut out, BitOrder::LsbFirst);
+        writer.write_bits(0b101, 3).unwrap();
+    }
+    assert_eq!(out.into_inner(), b"\x05");
+}
+
+#[test]
+fn map_stream() {
+    #[binrw]
+    #[brw(big)]
+    #[derive(Debug, PartialEq)]
+    struct Test {
+        #[br(map_stream = |r| BitReader::new(r, BitOrder::MsbFirst))]
+        #[bw(map_stream = |w| BitWriter::new(w, BitOrder::MsbFirst))]
+        a: u16,
+        b: u8,
+    }
+
+    let value = Test::read(&mut Cursor::new(b"\x12\x34\x56")).unwrap();
+    assert_eq!(value, Test { a: 0x1234, b: 0x56 });
+
+    let mut out = Cursor::new(Vec::new());
+    value.write(&mut out).unwrap();
+    assert_eq!(out.into_inner(), b"\x12\x34\x56");
+}
+
+#[test]
+fn packed() {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Debug, PartialEq)]
+    struct Test {
+        #[br(temp)]
+        #[bw(try_calc = u8::try_from(data.len()))]
+        len: u8,
+        #[br(parse_with = read_packed(len.into(), 12, BitOrder::LsbFirst))]
+        #[bw(write_with = write_packed(12, BitOrder::LsbFirst))]
+        data: Vec<u16>,
+        tail: u8,
+    }
+
+    let value = Test::read(&mut Cursor::new(b"\x03\xbc\xfa\xde\x23\x01\xff")).unwrap();
+    assert_eq!(
+        value,
+        Test {
+            data: vec![0xabc, 0xdef, 0x123],
+            tail: 0xff
+        }
+    );
+
+    let mut out = Cursor::new(Vec::new());
+    value.write(&mut out).unwrap();
+    assert_eq!(out.into_inner(), b"\x03\xbc\xfa\xde\x23\x01\xff");
+
+    let error = Test {
+        data: vec![0x1000],
+        tail: 0,
+    }
+    .write(&mut Cursor::new(Vec::new()))
+    .unwrap_err();
+    assert!(matches!(
+        error.root_cause(),
+        binrw::Error::AssertFail { pos: 1, message }
+            if message == "packed value 0x1000 does not fit in 12 bits"
+    ));
+
+    let error = read_packed::<_, u8, Vec<u8>>(1, 12, BitOrder::LsbFirst)(
+        &mut Cursor::new(b"\xff\x0f"),
+        binrw::Endian::Little,
+        (),
+    )
+    .unwrap_err();
+    assert!(matches!(error, binrw::Error::AssertFail { .. }));
+}
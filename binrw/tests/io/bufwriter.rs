@@ -0,0 +1,143 @@
+use binrw::io::{BufWriter, Cursor, Seek, SeekFrom, Write};
+
+struct Counter<T> {
+    inner: T,
+    writes: usize,
+    seeks: usize,
+}
+
+impl<T> Counter<T> {
+    fn new(inner: T) -> Self {
+        Counter {
+            inner,
+            writes: 0,
+            seeks: 0,
+        }
+    }
+}
+
+impl<T: Write> Write for Counter<T> {
+    fn write(&mut self, buf: &[u8]) -> binrw::io::Result<usize> {
+        self.writes += 1;
+        self.inner.write(buf)
+    }
+
+    fn flush(&mut self) -> binrw::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl<T: Seek> Seek for Counter<T> {
+    fn seek(&mut self, pos: SeekFrom) -> binrw::io::Result<u64> {
+        self.seeks += 1;
+        self.inner.seek(pos)
+    }
+}
+
+#[test]
+fn bufwriter() {
+    let mut stream = Cursor::new(b"xxxxx".to_vec());
+    // Give wrapped stream a non-zero position first to ensure it is adopted
+    // correctly by BufWriter
+    assert_eq!(stream.seek(SeekFrom::Start(5)).unwrap(), 5);
+
+    let mut stream = BufWriter::with_capacity(16, Counter::new(stream));
+    assert_eq!(stream.capacity(), 16);
+    assert_eq!(stream.stream_position().unwrap(), 5);
+    stream.get_mut().seeks = 0;
+
+    // Writes are buffered
+    stream.write_all(b"hello").unwrap();
+    stream.write_all(b"world").unwrap();
+    assert_eq!(stream.stream_position().unwrap(), 15);
+    assert_eq!(stream.buffer(), b"helloworld");
+    assert_eq!(stream.get_ref().writes, 0);
+
+    // Short seeks backwards and forwards do not touch the wrapped stream
+    assert_eq!(stream.seek(SeekFrom::Current(-5)).unwrap(), 10);
+    stream.write_all(b"W").unwrap();
+    assert_eq!(stream.seek(SeekFrom::Start(5)).unwrap(), 5);
+    stream.write_all(b"H").unwrap();
+    assert_eq!(stream.seek(SeekFrom::Start(15)).unwrap(), 15);
+    stream.write_all(b"!").unwrap();
+    assert_eq!(stream.buffer(), b"HelloWorld!");
+    assert_eq!(stream.get_ref().writes, 0);
+    assert_eq!(stream.get_ref().seeks, 0);
+
+    // A write outside of the buffered region flushes the buffer first
+    assert_eq!(stream.seek(SeekFrom::Start(0)).unwrap(), 0);
+    stream.write_all(b"abc").unwrap();
+    assert_eq!(stream.buffer(), b"abc");
+    assert_eq!(stream.get_ref().writes, 1);
+    assert_eq!(stream.get_ref().inner.get_ref(), b"xxxxxHelloWorld!");
+
+    // A write which does not fit in the remaining capacity flushes the buffer
+    stream.write_all(b"defghijklmnop").unwrap();
+    assert_eq!(stream.buffer(), b"abcdefghijklmnop");
+    stream.write_all(b"q").unwrap();
+    assert_eq!(stream.buffer(), b"q");
+    assert_eq!(stream.get_ref().writes, 2);
+
+    // A write at least as large as the buffer bypasses it
+    stream.write_all(b"0123456789abcdefg").unwrap();
+    assert_eq!(stream.buffer(), b"");
+    assert_eq!(stream.stream_position().unwrap(), 34);
+
+    // Seeking from the end includes the buffered data
+    stream.write_all(b"rs").unwrap();
+    assert_eq!(stream.seek(SeekFrom::End(0)).unwrap(), 36);
+    assert_eq!(stream.seek(SeekFrom::End(-2)).unwrap(), 34);
+    stream.write_all(b"RS").unwrap();
+
+    let counter = stream.into_inner().unwrap();
+    assert_eq!(counter.inner.position(), 36);
+    assert_eq!(
+        counter.inner.into_inner(),
+        b"abcdefghijklmnopq0123456789abcdefgRS"
+    );
+}
+
+#[test]
+fn bufwriter_invalid_seek() {
+    let mut stream = BufWriter::new(Cursor::new(Vec::new()));
+    stream.write_all(b"hello").unwrap();
+    assert!(stream.seek(SeekFrom::Current(-6)).is_err());
+    assert_eq!(stream.stream_position().unwrap(), 5);
+}
+
+#[test]
+fn bufwriter_drop_flushes() {
+    let mut data = Cursor::new(Vec::new());
+    {
+        let mut stream = BufWriter::new(&mut data);
+        stream.write_all(b"world").unwrap();
+        stream.seek(SeekFrom::Start(0)).unwrap();
+        stream.write_all(b"hello").unwrap();
+        stream.write_all(b"!").unwrap();
+    }
+    assert_eq!(data.into_inner(), b"hello!");
+}
+
+#[test]
+fn bufwriter_derive() {
+    use binrw::{binwrite, BinWrite};
+
+    #[binwrite]
+    #[bw(big)]
+    struct Test {
+        #[bw(restore_position)]
+        a: u16,
+        b: u32,
+        #[bw(pad_before = 2, align_after = 8)]
+        c: u8,
+    }
+
+    let mut out = Cursor::new(Vec::new());
+    let mut stream = BufWriter::new(Counter::new(&mut out));
+    Test { a: 1, b: 2, c: 3 }.write(&mut stream).unwrap();
+    assert_eq!(stream.get_ref().writes, 0);
+    stream.flush().unwrap();
+    assert_eq!(stream.get_ref().writes, 1);
+    drop(stream);
+    assert_eq!(out.into_inner(), b"\0\0\0\x02\0\0\x03\0");
+}
@@ -0,0 +1,71 @@
+#![allow(clippy::single_range_in_vec_init)]
+use binrw::{
+    io::{CoverageReader, Cursor, Read, Seek, SeekFrom},
+    BinRead,
+};
+
+#[test]
+fn coverage() {
+    let mut stream = Cursor::new(b"0123456789".to_vec());
+    // Give wrapped stream a non-zero position first to ensure it is adopted
+    // correctly by CoverageReader
+    stream.set_position(2);
+    let mut stream = CoverageReader::new(stream);
+    let mut buf = [0; 2];
+
+    stream.read_exact(&mut buf).unwrap();
+    assert_eq!(&buf, b"23");
+    assert_eq!(stream.read_ranges(), &[2..4]);
+
+    // Sequential reads are merged
+    stream.read_exact(&mut buf).unwrap();
+    assert_eq!(stream.read_ranges(), &[2..6]);
+
+    // Disjoint reads are kept separate and sorted
+    stream.seek(SeekFrom::Start(8)).unwrap();
+    stream.read_exact(&mut buf).unwrap();
+    stream.seek(SeekFrom::Start(0)).unwrap();
+    stream.read_exact(&mut [0]).unwrap();
+    assert_eq!(stream.read_ranges(), &[0..1, 2..6, 8..10]);
+    assert_eq!(stream.unread(10), vec![1..2, 6..8]);
+    assert_eq!(stream.unread(12), vec![1..2, 6..8, 10..12]);
+    assert_eq!(stream.unread(7), vec![1..2, 6..7]);
+    assert_eq!(stream.unread(0), vec![]);
+
+    // Reads at the end of the stream do not record anything
+    stream.seek(SeekFrom::End(0)).unwrap();
+    assert_eq!(stream.read(&mut buf).unwrap(), 0);
+    assert_eq!(stream.read_ranges(), &[0..1, 2..6, 8..10]);
+
+    // Overlapping reads merge all of the ranges they touch
+    stream.seek(SeekFrom::Start(5)).unwrap();
+    stream.read_exact(&mut [0; 4]).unwrap();
+    assert_eq!(stream.read_ranges(), &[0..1, 2..10]);
+    assert_eq!(stream.stream_position().unwrap(), 9);
+    assert_eq!(stream.unread_to_end().unwrap(), vec![1..2]);
+    assert_eq!(stream.stream_position().unwrap(), 9);
+
+    stream.clear();
+    assert_eq!(stream.unread_to_end().unwrap(), vec![0..10]);
+    assert_eq!(stream.into_inner().position(), 9);
+}
+
+#[test]
+fn coverage_derive() {
+    #[derive(BinRead)]
+    #[br(big)]
+    struct Test {
+        a: u16,
+        #[br(pad_before = 1)]
+        b: u8,
+        #[br(restore_position)]
+        c: u32,
+        d: u8,
+    }
+
+    let mut stream = CoverageReader::new(Cursor::new(b"\0\x01\xff\x02\0\0\0\x03\xff\xff"));
+    let value = Test::read(&mut stream).unwrap();
+    assert_eq!((value.a, value.b, value.c, value.d), (1, 2, 3, 0));
+    assert_eq!(stream.read_ranges(), &[0..2, 3..8]);
+    assert_eq!(stream.unread_to_end().unwrap(), vec![2..3, 8..10]);
+}
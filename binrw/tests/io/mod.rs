@@ -1,5 +1,8 @@
+mod bits;
 #[cfg(feature = "std")]
 mod bufreader;
+mod bufwriter;
+mod coverage;
 #[cfg(not(feature = "std"))]
 mod no_std;
 mod seek;
@@ -1,5 +1,5 @@
 #![allow(clippy::seek_to_start_instead_of_rewind)]
-use binrw::io::{NoSeek, Read, Seek, SeekFrom, Write};
+use binrw::io::{NoSeek, NoSeekWriter, Read, Seek, SeekFrom, Write};
 
 #[test]
 fn read() {
@@ -114,3 +114,62 @@ fn write_vectored() {
     assert_eq!(stream.get_ref(), b"aaaaaaaaaa");
     assert_eq!(stream.stream_position().unwrap(), 10);
 }
+
+#[test]
+fn writer() {
+    let mut stream = NoSeekWriter::new(Vec::new());
+    assert_eq!(stream.write(b"hello").unwrap(), 5);
+    assert_eq!(stream.stream_position().unwrap(), 5);
+    assert_eq!(stream.seek(SeekFrom::Start(5)).unwrap(), 5);
+    assert_eq!(stream.get_ref(), b"hello");
+
+    // Forward seeks write zeroes
+    assert_eq!(stream.seek(SeekFrom::Start(7)).unwrap(), 7);
+    assert_eq!(stream.seek(SeekFrom::Current(1)).unwrap(), 8);
+    assert_eq!(stream.seek(SeekFrom::End(1)).unwrap(), 9);
+    assert_eq!(stream.seek(SeekFrom::Current(0x41)).unwrap(), 0x4a);
+    assert_eq!(stream.get_ref().len(), 0x4a);
+    assert!(stream.get_ref()[5..].iter().all(|b| *b == 0));
+
+    // Backward seeks fail without writing anything
+    stream.seek(SeekFrom::Start(0)).unwrap_err();
+    stream.seek(SeekFrom::Current(-1)).unwrap_err();
+    stream.seek(SeekFrom::End(-1)).unwrap_err();
+    assert_eq!(stream.stream_position().unwrap(), 0x4a);
+
+    stream.write_all(b"world").unwrap();
+    assert_eq!(stream.stream_position().unwrap(), 0x4f);
+    let out = stream.into_inner();
+    assert_eq!(&out[..5], b"hello");
+    assert_eq!(&out[0x4a..], b"world");
+}
+
+#[test]
+fn writer_derive() {
+    use binrw::{binwrite, BinWrite};
+
+    #[binwrite]
+    #[bw(little)]
+    struct Test {
+        a: u8,
+        #[bw(seek_before = SeekFrom::Start(4))]
+        b: u16,
+        #[bw(seek_before = SeekFrom::Current(2), align_after = 4)]
+        c: u8,
+    }
+
+    #[binwrite]
+    #[bw(little)]
+    struct BackPatch {
+        #[bw(restore_position)]
+        a: u8,
+        b: u8,
+    }
+
+    let mut stream = NoSeekWriter::new(Vec::new());
+    Test { a: 1, b: 2, c: 3 }.write(&mut stream).unwrap();
+    assert_eq!(stream.into_inner(), b"\x01\0\0\0\x02\0\0\0\x03\0\0\0");
+
+    let mut stream = NoSeekWriter::new(Vec::new());
+    BackPatch { a: 1, b: 2 }.write(&mut stream).unwrap_err();
+}
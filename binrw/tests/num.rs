@@ -0,0 +1,258 @@
+use binrw::{
+    binrw,
+    io::Cursor,
+    num::{Bf16, Fixed, F16, I24, I40, I48, I56, Q16_16, Q2_14, Q8_8, U24, U40, U48, U56},
+    BinRead, BinReaderExt, BinWrite,
+};
+
+#[test]
+fn f16_round_trip() {
+    for bits in 0..=u16::MAX {
+        let value = F16::from_bits(bits);
+        let wide = value.to_f64();
+        if wide.is_nan() {
+            assert!(value.to_f32().is_nan());
+            assert!(F16::from_f64(wide).to_f64().is_nan());
+        } else {
+            assert_eq!(F16::from_f64(wide).to_bits(), bits);
+            assert_eq!(F16::from_f32(value.to_f32()).to_bits(), bits);
+        }
+    }
+}
+
+#[test]
+fn f16_from_float() {
+    assert_eq!(F16::from_f32(1.0).to_bits(), 0x3c00);
+    assert_eq!(F16::from_f32(-2.5).to_bits(), 0xc100);
+    assert_eq!(F16::from_f32(-0.0).to_bits(), 0x8000);
+    assert_eq!(F16::from_f32(65504.0).to_bits(), 0x7bff);
+    assert_eq!(F16::from_f32(65519.0).to_bits(), 0x7bff);
+    assert_eq!(F16::from_f32(65520.0).to_bits(), 0x7c00);
+    assert_eq!(F16::from_f64(1e300).to_bits(), 0x7c00);
+    assert_eq!(F16::from_f32(f32::NEG_INFINITY).to_bits(), 0xfc00);
+    assert!(F16::from_f32(f32::NAN).to_f32().is_nan());
+
+    // Ties round to even
+    assert_eq!(F16::from_f64(1.0 + 2f64.powi(-11)).to_bits(), 0x3c00);
+    assert_eq!(F16::from_f64(1.0 + 3.0 * 2f64.powi(-11)).to_bits(), 0x3c02);
+    assert_eq!(
+        F16::from_f64(1.0 + 2f64.powi(-11) + 2f64.powi(-40)).to_bits(),
+        0x3c01
+    );
+
+    // Subnormals
+    assert_eq!(F16::from_f64(2f64.powi(-24)).to_bits(), 0x0001);
+    assert_eq!(F16::from_f64(2f64.powi(-25)).to_bits(), 0x0000);
+    assert_eq!(F16::from_f64(1.5 * 2f64.powi(-25)).to_bits(), 0x0001);
+    assert_eq!(F16::from_f64(-2f64.powi(-26)).to_bits(), 0x8000);
+    assert_eq!(
+        F16::from_f64(2f64.powi(-14) - 2f64.powi(-25)).to_bits(),
+        0x0400
+    );
+    assert_eq!(F16::from_bits(0x03ff).to_f64(), 1023.0 * 2f64.powi(-24));
+    assert_eq!(F16::from_f64(f64::MIN_POSITIVE / 2.0).to_bits(), 0x0000);
+}
+
+#[test]
+fn bf16() {
+    for bits in 0..=u16::MAX {
+        let value = Bf16::from_bits(bits);
+        if !value.to_f32().is_nan() {
+            assert_eq!(value.to_f32().to_bits(), u32::from(bits) << 16);
+            assert_eq!(Bf16::from_f32(value.to_f32()).to_bits(), bits);
+        }
+    }
+
+    assert_eq!(Bf16::from_f32(core::f32::consts::PI).to_bits(), 0x4049);
+    assert_eq!(
+        Bf16::from_f32(f32::from_bits(0x3f80_8000)).to_bits(),
+        0x3f80
+    );
+    assert_eq!(
+        Bf16::from_f32(f32::from_bits(0x3f81_8000)).to_bits(),
+        0x3f82
+    );
+    assert_eq!(
+        Bf16::from_f32(f32::from_bits(0x3f80_8001)).to_bits(),
+        0x3f81
+    );
+    assert_eq!(Bf16::from_f32(f32::MAX).to_bits(), 0x7f80);
+    assert_eq!(Bf16::from_f32(f32::from_bits(1)).to_bits(), 0x0000);
+    assert!(Bf16::from_f32(f32::NAN).to_f32().is_nan());
+    assert_eq!(Bf16::from_f32(1.0), Bf16::from_f64(1.0));
+    assert_ne!(Bf16::from_f32(f32::NAN), Bf16::from_f32(f32::NAN));
+    assert_eq!(Bf16::from_f32(0.0), Bf16::from_f32(-0.0));
+    assert_eq!(
+        format!("{:?} {}", Bf16::from_f32(1.5), F16::from_f32(-2.0)),
+        "1.5 -2"
+    );
+}
+
+#[test]
+fn fixed() {
+    assert_eq!(Q16_16::from_f64(1.5).to_bits(), 0x1_8000);
+    assert_eq!(Q16_16::from_f64(-1.5).to_bits(), -0x1_8000);
+    assert_eq!(Q16_16::from_bits(-1).to_f64(), -(2f64.powi(-16)));
+    assert_eq!(Q8_8::from_f32(127.99).to_bits(), 0x7ffd);
+    assert_eq!(Q8_8::from_f32(1000.0).to_bits(), i16::MAX);
+    assert_eq!(Q8_8::from_f32(-1000.0).to_bits(), i16::MIN);
+    assert_eq!(Q8_8::from_f32(f32::NAN).to_bits(), 0);
+    assert_eq!(f32::from(Q2_14::from_bits(0x7fff)), 1.999_939);
+    assert_eq!(Q2_14::from_f32(-2.0).to_bits(), i16::MIN);
+    assert_eq!(Q16_16::from_f64(f64::INFINITY).to_bits(), i32::MAX);
+    assert_eq!(Q16_16::from_f64(f64::NEG_INFINITY).to_bits(), i32::MIN);
+    assert_eq!(Q16_16::from_f64(1e40).to_bits(), i32::MAX);
+    assert_eq!(Q16_16::from_f64(-1e40).to_bits(), i32::MIN);
+    assert_eq!(Q16_16::from_f64(f64::NAN).to_bits(), 0);
+
+    // Ties round away from zero
+    assert_eq!(Fixed::<u8, 0>::from_f64(2.5).to_bits(), 3);
+    assert_eq!(Fixed::<i8, 0>::from_f64(-2.5).to_bits(), -3);
+    assert_eq!(Fixed::<i8, 0>::from_f64(-2.4).to_bits(), -2);
+    assert_eq!(Fixed::<u32, 32>::from_f64(0.5).to_bits(), 0x8000_0000);
+    assert_eq!(Fixed::<u16, 8>::from_f64(-1.0).to_bits(), 0);
+
+    assert!(Q8_8::from_f32(1.0) < Q8_8::from_f32(1.5));
+    assert_eq!(
+        format!("{:?} {}", Q16_16::from_f64(-0.25), Q8_8::from_f32(3.0)),
+        "-0.25 3"
+    );
+}
+
+#[test]
+fn read_write() {
+    #[derive(BinRead, BinWrite, Debug, PartialEq)]
+    #[brw(big)]
+    struct Test {
+        half: F16,
+        #[brw(little)]
+        brain: Bf16,
+        fixed: Q16_16,
+        #[brw(little)]
+        small: Q2_14,
+    }
+
+    let data = b"\x3c\x00\x49\x40\xff\xfe\x80\x00\x00\x60";
+    let value = Test::read(&mut Cursor::new(data)).unwrap();
+    assert_eq!(
+        value,
+        Test {
+            half: F16::from_f32(1.0),
+            brain: Bf16::from_f32(3.140625),
+            fixed: Q16_16::from_f64(-1.5),
+            small: Q2_14::from_f32(1.5),
+        }
+    );
+
+    let mut out = Cursor::new(Vec::new());
+    value.write(&mut out).unwrap();
+    assert_eq!(out.into_inner(), data);
+}
+
+#[test]
+fn odd_int_read_write() {
+    #[derive(BinRead, BinWrite, Debug, PartialEq)]
+    #[brw(big)]
+    struct Test {
+        a: U24,
+        #[brw(little)]
+        b: I24,
+        c: U40,
+        #[brw(little)]
+        d: I48,
+        e: U56,
+        f: I40,
+        g: U48,
+        h: I56,
+    }
+
+    let data = b"\x01\x02\x03\xfe\xff\xff\x01\x02\x03\x04\x05\x00\x00\x00\x00\x00\x80\x01\x02\x03\x04\x05\x06\x07\xff\xff\xff\xff\xfe\x12\x34\x56\x78\x9a\xbc\x7f\xff\xff\xff\xff\xff\xff";
+    let value = Test::read(&mut Cursor::new(data)).unwrap();
+    assert_eq!(
+        value,
+        Test {
+            a: U24::new(0x01_0203).unwrap(),
+            b: I24::from(-2_i8),
+            c: U40::new(0x01_0203_0405).unwrap(),
+            d: I48::MIN,
+            e: U56::new(0x01_0203_0405_0607).unwrap(),
+            f: I40::from(-2_i8),
+            g: U48::new(0x1234_5678_9abc).unwrap(),
+            h: I56::MAX,
+        }
+    );
+
+    let mut out = Cursor::new(Vec::new());
+    value.write(&mut out).unwrap();
+    assert_eq!(out.into_inner(), data);
+
+    let mut data = Cursor::new(b"\x01\x02");
+    data.read_be::<U24>().unwrap_err();
+    assert_eq!(data.position(), 0);
+}
+
+#[test]
+fn odd_int_conversions() {
+    assert_eq!(U24::MAX.get(), 0xff_ffff);
+    assert_eq!(U24::MIN.get(), 0);
+    assert_eq!(I24::MAX.get(), 0x7f_ffff);
+    assert_eq!(I24::MIN.get(), -0x80_0000);
+    assert_eq!(U40::MAX.get(), 0xff_ffff_ffff);
+    assert_eq!(I56::MIN.get(), -0x80_0000_0000_0000);
+    assert_eq!(U24::BITS, 24);
+
+    assert_eq!(U24::new(0x100_0000), None);
+    assert_eq!(I24::new(-0x80_0001), None);
+    assert_eq!(U24::try_from(0xff_ffff_u32).unwrap(), U24::MAX);
+    U24::try_from(0x100_0000_usize).unwrap_err();
+    U24::try_from(-1_i32).unwrap_err();
+    assert_eq!(I24::try_from(-0x80_0000_i64).unwrap(), I24::MIN);
+    assert_eq!(
+        U48::try_from(-1_i8).unwrap_err().to_string(),
+        "out of range integral type conversion attempted"
+    );
+    assert_eq!(U24::from(0xffff_u16).get(), 0xffff);
+    assert_eq!(I40::from(i32::MIN).get(), i64::from(i32::MIN));
+
+    assert_eq!(u32::from(U24::MAX), 0xff_ffff);
+    assert_eq!(i32::from(U24::MAX), 0xff_ffff);
+    assert_eq!(i64::from(U56::MAX), 0xff_ffff_ffff_ffff);
+    assert_eq!(i128::from(I24::MIN), -0x80_0000);
+    assert_eq!(usize::try_from(U24::MAX).unwrap(), 0xff_ffff);
+    u16::try_from(U24::MAX).unwrap_err();
+    u64::try_from(I56::from(-1_i8)).unwrap_err();
+    assert_eq!(u8::try_from(I40::from(5_u8)).unwrap(), 5);
+
+    assert!(I24::MIN < I24::MAX);
+    assert_eq!(
+        format!("{} {:?}", I24::MIN, U24::MAX),
+        "-8388608 U24(16777215)"
+    );
+}
+
+#[test]
+fn odd_int_collections() {
+    #[binrw]
+    #[brw(little)]
+    #[derive(Debug, PartialEq)]
+    struct Test {
+        #[br(temp)]
+        #[bw(try_calc = U24::try_from(items.len()))]
+        len: U24,
+        #[br(count = len)]
+        items: Vec<I24>,
+    }
+
+    let data = b"\x02\0\0\xff\xff\xff\x01\0\0";
+    let value = Test::read(&mut Cursor::new(data)).unwrap();
+    assert_eq!(
+        value,
+        Test {
+            items: vec![I24::from(-1_i8), I24::from(1_u8)]
+        }
+    );
+
+    let mut out = Cursor::new(Vec::new());
+    value.write(&mut out).unwrap();
+    assert_eq!(out.into_inner(), data);
+}
@@ -0,0 +1,63 @@
+extern crate alloc;
+
+use alloc::format;
+use binrw::{binrw, io::Cursor, BinRead, BinWrite, NullString, Patchable};
+
+#[test]
+fn patchable() {
+    #[binrw]
+    #[derive(Clone, Debug, PartialEq)]
+    struct Inner {
+        a: u8,
+        #[brw(align_before = 4)]
+        b: u16,
+    }
+
+    #[binrw]
+    #[brw(little)]
+    struct Test {
+        magic: [u8; 2],
+        inner: Patchable<Inner>,
+        name: Patchable<NullString>,
+        tail: u8,
+    }
+
+    let data = b"MZ\x01\0\x02\0abc\0\xff";
+    let mut file = Cursor::new(data.to_vec());
+    let mut value = Test::read(&mut file).unwrap();
+    assert_eq!(value.inner.pos, 2);
+    assert_eq!(value.inner.size, 4);
+    assert_eq!(*value.inner, Inner { a: 1, b: 2 });
+    assert_eq!(value.inner, Inner { a: 1, b: 2 });
+    assert_eq!(format!("{:?}", value.inner), "Inner { a: 1, b: 2 }");
+    assert_eq!(value.name.pos, 6);
+    assert_eq!(value.name.size, 4);
+    assert_eq!(value.inner.clone().size, 4);
+
+    // A patched subtree is encoded relative to its original position, and the
+    // position of the writer is restored afterwards
+    value.inner.a = 3;
+    value.inner.b = 0x405;
+    value.inner.patch_le(&mut file).unwrap();
+    assert_eq!(file.position(), data.len() as u64);
+    assert_eq!(file.get_ref(), b"MZ\x03\0\x05\x04abc\0\xff");
+
+    *value.name = NullString::from("xyz");
+    value.name.patch_le(&mut file).unwrap();
+    assert_eq!(file.get_ref(), b"MZ\x03\0\x05\x04xyz\0\xff");
+
+    // A value whose new encoding has a different size is not written
+    *value.name = NullString::from("toolong");
+    let err = value.name.patch_le(&mut file).unwrap_err();
+    assert!(matches!(
+        err,
+        binrw::Error::AssertFail { pos: 6, message }
+            if message == "patched value is 8 bytes, but the original value is 4 bytes"
+    ));
+    assert_eq!(file.get_ref(), b"MZ\x03\0\x05\x04xyz\0\xff");
+
+    // Writing normally ignores the recorded position and size
+    let mut out = Cursor::new(Vec::new());
+    value.write(&mut out).unwrap();
+    assert_eq!(out.into_inner(), b"MZ\x03\0\x05\x04toolong\0\xff");
+}
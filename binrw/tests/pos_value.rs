@@ -1,7 +1,7 @@
 extern crate alloc;
 
 use alloc::format;
-use binrw::{io::Cursor, BinRead, BinReaderExt, BinWrite, PosValue};
+use binrw::{io::Cursor, BinRead, BinReaderExt, BinWrite, PosValue, Spanned};
 
 #[test]
 fn pos_value() {
@@ -40,3 +40,57 @@ fn pos_value() {
     assert_eq!(from.a, val.a);
     assert_eq!(from.b, *val.b);
 }
+
+#[test]
+fn spanned() {
+    #[derive(BinRead, BinWrite, Default)]
+    struct MyType {
+        len: u8,
+        #[br(count = len)]
+        a: Spanned<Vec<u16>>,
+        #[br(assert(b.byte_len() == 2, "bad length"))]
+        b: Spanned<u16>,
+        c: Spanned<()>,
+    }
+
+    let mut val: MyType = Cursor::new(b"\x02\0\x01\0\x02\xFF\xFE")
+        .read_be::<MyType>()
+        .unwrap();
+    assert_eq!(val.a.start, 1);
+    assert_eq!(val.a.end, 5);
+    assert_eq!(val.a.byte_len(), 4);
+    assert_eq!(val.a.len(), 2);
+    assert_eq!(val.a.range(), 1..5);
+    assert!(!val.a.range().is_empty());
+    assert_eq!(*val.a, [1, 2]);
+    assert_eq!(val.a, vec![1, 2]);
+    assert_eq!(val.b.range(), 5..7);
+    assert_eq!(val.c.range(), 7..7);
+    assert_eq!(val.c.byte_len(), 0);
+
+    val.a.push(3);
+    *val.b = 1;
+    assert_eq!(format!("{:?}", val.a), "[1, 2, 3]");
+    let clone = val.b.clone();
+    assert_eq!(*clone, *val.b);
+    assert_eq!(clone.range(), val.b.range());
+
+    let mut output = Vec::new();
+    val.write_be(&mut Cursor::new(&mut output)).unwrap();
+    assert_eq!(output, b"\x02\0\x01\0\x02\0\x03\0\x01");
+
+    let default_val = MyType::default();
+    assert_eq!(default_val.b.range(), 0..0);
+    let from: Spanned<u8> = 5.into();
+    assert_eq!(from, 5u8);
+    assert_eq!(from.byte_len(), 0);
+
+    let reversed = Spanned {
+        val: 0u8,
+        start: 5,
+        end: 2,
+    };
+    assert_eq!(reversed.byte_len(), 0);
+
+    assert!(Cursor::new(b"\x00\xFF").read_be::<MyType>().is_err());
+}
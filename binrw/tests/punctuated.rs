@@ -1,14 +1,14 @@
 extern crate alloc;
 
 use alloc::format;
-use binrw::{io::Cursor, punctuated::Punctuated, BinRead, BinReaderExt};
+use binrw::{binrw, io::Cursor, punctuated::Punctuated, BinRead, BinReaderExt, BinWrite};
 
-#[derive(BinRead, Clone, Copy, Debug)]
-#[br(magic = 1u8)]
+#[derive(BinRead, BinWrite, Clone, Copy, Debug)]
+#[brw(magic = 1u8)]
 struct One;
 
-#[derive(BinRead, Clone, Copy, Debug)]
-#[br(magic = 2u8)]
+#[derive(BinRead, BinWrite, Clone, Copy, Debug)]
+#[brw(magic = 2u8)]
 struct Two;
 
 #[derive(BinRead)]
@@ -89,3 +89,78 @@ fn punctuated_trailing() {
 //
 //     let _: MissingCountTrailing = x.read_be().unwrap();
 // }
+
+#[test]
+fn punctuated_write() {
+    #[binrw]
+    struct Test {
+        #[br(temp)]
+        #[bw(calc = list.len() as u8)]
+        count: u8,
+
+        #[br(count = count)]
+        #[br(parse_with = Punctuated::<One, Two>::separated)]
+        #[bw(write_with = Punctuated::<One, Two>::write_separated)]
+        list: Punctuated<One, Two>,
+    }
+
+    #[binrw]
+    struct TestTrailing {
+        #[br(temp)]
+        #[bw(calc = list.len() as u8)]
+        count: u8,
+
+        #[br(count = count)]
+        #[br(parse_with = Punctuated::<One, Two>::separated_trailing)]
+        #[bw(write_with = Punctuated::<One, Two>::write_separated_trailing)]
+        list: Punctuated<One, Two>,
+    }
+
+    let value: Test = Cursor::new(TEST_DATA).read_be().unwrap();
+    let mut out = Cursor::new(Vec::new());
+    value.write_be(&mut out).unwrap();
+    assert_eq!(out.into_inner(), TEST_DATA);
+
+    let value: TestTrailing = Cursor::new(TEST_DATA_TRAILING).read_be().unwrap();
+    let mut out = Cursor::new(Vec::new());
+    value.write_be(&mut out).unwrap();
+    assert_eq!(out.into_inner(), TEST_DATA_TRAILING);
+
+    // The plain `BinWrite` implementation writes whichever separators exist
+    let mut out = Cursor::new(Vec::new());
+    value.list.write_be(&mut out).unwrap();
+    assert_eq!(out.into_inner(), &TEST_DATA_TRAILING[1..]);
+
+    // The wrong number of separators for the format is an error
+    let error = Test { list: value.list }
+        .write_be(&mut Cursor::new(Vec::new()))
+        .unwrap_err();
+    assert!(matches!(
+        error.root_cause(),
+        binrw::Error::AssertFail { pos: 1, message }
+            if message == "expected 2 separators for 3 values, but found 3"
+    ));
+}
+
+#[test]
+fn punctuated_from_values() {
+    let list = Punctuated::<One, Two>::from_values(vec![One; 3], Two);
+    assert_eq!(list.separators.len(), 2);
+    let mut out = Cursor::new(Vec::new());
+    list.write_be(&mut out).unwrap();
+    assert_eq!(out.into_inner(), &TEST_DATA[1..]);
+
+    let list = Punctuated::<One, Two>::from_values_trailing(vec![One; 3], Two);
+    let mut out = Cursor::new(Vec::new());
+    Punctuated::write_separated_trailing(&list, &mut out, binrw::Endian::Big, ()).unwrap();
+    assert_eq!(out.into_inner(), &TEST_DATA_TRAILING[1..]);
+
+    let list = Punctuated::<One, Two>::from_values(Vec::new(), Two);
+    assert!(list.separators.is_empty());
+    let mut out = Cursor::new(Vec::new());
+    Punctuated::write_separated(&list, &mut out, binrw::Endian::Big, ()).unwrap();
+    assert!(out.into_inner().is_empty());
+
+    let list = Punctuated::<One, Two>::new(vec![One], vec![Two; 2]);
+    list.write_be(&mut Cursor::new(Vec::new())).unwrap_err();
+}
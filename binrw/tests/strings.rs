@@ -152,3 +152,102 @@ fn null_wide_string_round_trip() {
 
     assert_eq!(&s2.to_string(), data);
 }
+
+#[test]
+fn strings() {
+    use binrw::{args, io::Cursor, BinRead, BinWrite, Encoding, Endian};
+
+    let read = |data: &[u8], endian, count, encoding| {
+        String::read_options(&mut Cursor::new(data), endian, args! { count, encoding })
+    };
+
+    assert_eq!(
+        read(b"h\xc3\xa9llo", Endian::Little, 6, Encoding::Utf8).unwrap(),
+        "héllo"
+    );
+    assert_eq!(
+        read(b"\0h\0i", Endian::Big, 4, Encoding::Utf16).unwrap(),
+        "hi"
+    );
+    assert_eq!(
+        read(b"h\0\0\0i\0\0\0", Endian::Little, 8, Encoding::Utf32).unwrap(),
+        "hi"
+    );
+    assert_eq!(
+        read(b"h\xe9", Endian::Little, 2, Encoding::Latin1).unwrap(),
+        "hé"
+    );
+    assert!(matches!(
+        read(b"\xff", Endian::Little, 1, Encoding::Utf8).expect_err("accepted bad data"),
+        binrw::Error::AssertFail { pos: 0, .. }
+    ));
+    assert!(matches!(
+        read(b"\0\xd8\0\0", Endian::Little, 4, Encoding::Utf32).expect_err("accepted bad data"),
+        binrw::Error::AssertFail { pos: 0, message } if message == "invalid Utf32 string: invalid code point 0xd800"
+    ));
+    read(b"\0h\0", Endian::Big, 3, Encoding::Utf16).expect_err("accepted bad count");
+    assert!(read(b"ab", Endian::Little, 3, Encoding::Utf8)
+        .unwrap_err()
+        .is_eof());
+
+    let write = |s: &str, endian, encoding| {
+        let mut out = Cursor::new(Vec::new());
+        s.to_string()
+            .write_options(&mut out, endian, encoding)
+            .map(|()| out.into_inner())
+    };
+
+    assert_eq!(
+        write("héllo", Endian::Little, Encoding::Utf8).unwrap(),
+        b"h\xc3\xa9llo"
+    );
+    assert_eq!(
+        write("hi", Endian::Big, Encoding::Utf16).unwrap(),
+        b"\0h\0i"
+    );
+    assert_eq!(
+        write("hi", Endian::Little, Encoding::Utf32).unwrap(),
+        b"h\0\0\0i\0\0\0"
+    );
+    assert_eq!(
+        write("hé", Endian::Little, Encoding::Latin1).unwrap(),
+        b"h\xe9"
+    );
+    assert!(matches!(
+        write("h☺", Endian::Little, Encoding::Latin1).expect_err("accepted bad data"),
+        binrw::Error::AssertFail { pos: 1, message } if message == "character '☺' cannot be encoded as Latin1"
+    ));
+}
+
+#[test]
+fn chars() {
+    use binrw::{io::Cursor, BinRead, BinWrite, Encoding, Endian};
+
+    let round_trip = |c: char, data: &[u8], endian, encoding| {
+        assert_eq!(
+            char::read_options(&mut Cursor::new(data), endian, encoding).unwrap(),
+            c
+        );
+        let mut out = Cursor::new(Vec::new());
+        c.write_options(&mut out, endian, encoding).unwrap();
+        assert_eq!(out.into_inner(), data);
+    };
+
+    round_trip('a', b"a", Endian::Little, Encoding::Utf8);
+    round_trip('☺', b"\xe2\x98\xba", Endian::Little, Encoding::Utf8);
+    round_trip('☺', b"\x3a\x26", Endian::Little, Encoding::Utf16);
+    round_trip('😀', b"\xd8\x3d\xde\x00", Endian::Big, Encoding::Utf16);
+    round_trip('😀', b"\0\x01\xf6\0", Endian::Big, Encoding::Utf32);
+    round_trip('é', b"\xe9", Endian::Big, Encoding::Latin1);
+
+    char::read_options(&mut Cursor::new(b"\xff"), Endian::Little, Encoding::Utf8)
+        .expect_err("accepted bad data");
+    char::read_options(&mut Cursor::new(b"\0\xdc"), Endian::Little, Encoding::Utf16)
+        .expect_err("accepted bad data");
+    char::read_options(
+        &mut Cursor::new(b"\0\xd8\0\0"),
+        Endian::Big,
+        Encoding::Utf32,
+    )
+    .expect_err("accepted bad data");
+}
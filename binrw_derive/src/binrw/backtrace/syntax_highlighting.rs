@@ -195,6 +195,10 @@ fn visit_expr_attributes(field: &StructField, visitor: &mut Visitor) {
         pad_size_to
     );
 
+    if let Some(take) = field.take.clone() {
+        visit!(take.len);
+    }
+
     if let Some(condition) = field.if_cond.clone() {
         let Condition {
             condition,
@@ -394,7 +398,7 @@ fn is_keyword_ident(ident: &syn::Ident) -> bool {
         bw, calc, count, default, ignore, import, import_raw, is_big, is_little,
         little, magic, map, offset, pad_after, pad_before, pad_size_to, parse_with,
         pre_assert, repr, restore_position, return_all_errors,
-        return_unexpected_error, seek_before, temp, try_map, write_with
+        return_unexpected_error, seek_before, take, take_exact, temp, try_map, write_with
     );
 
     is_keyword
@@ -1,7 +1,9 @@
-use super::sanitization::{META_ENDIAN_KIND, READ_ENDIAN, READ_MAGIC, WRITE_ENDIAN, WRITE_MAGIC};
-use crate::binrw::parser::{CondEndian, Input, Map};
+use super::sanitization::{
+    META_ENDIAN_KIND, READ_ENDIAN, READ_MAGIC, TEMP, WRITE_ENDIAN, WRITE_MAGIC, WRITE_TAG,
+};
+use crate::binrw::parser::{CondEndian, EnumVariant, Input, Map};
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{quote, ToTokens};
 
 pub(crate) fn generate<const WRITE: bool>(
     input: &Input,
@@ -34,6 +36,9 @@ pub(crate) fn generate<const WRITE: bool>(
             Map::Map(_) | Map::Try(_) => Some(quote! {
                 #META_ENDIAN_KIND::None
             }),
+            Map::Repr(_) if matches!(input, Input::Enum(en) if en.discriminant_repr().is_some()) => {
+                None
+            }
             Map::Repr(repr) => ["i8", "u8"].contains(&repr.to_string().as_str()).then(|| {
                 quote! { <(#repr) as #endian_meta>::ENDIAN }
             }),
@@ -41,7 +46,7 @@ pub(crate) fn generate<const WRITE: bool>(
         CondEndian::Fixed(endian) => Some(quote! {
             #META_ENDIAN_KIND::Endian(#endian)
         }),
-        CondEndian::Cond(..) => Some(quote! {
+        CondEndian::Cond(..) | CondEndian::Marker { .. } => Some(quote! {
             #META_ENDIAN_KIND::Runtime
         }),
     }
@@ -53,8 +58,46 @@ pub(crate) fn generate<const WRITE: bool>(
         }
     });
 
+    let tag = match input {
+        Input::Enum(en) if WRITE => en.tag_type.as_ref().map(|ty| {
+            let arms = en.variants.iter().map(|variant| {
+                let ident = variant.ident();
+                match variant {
+                    EnumVariant::Variant { options, .. } if variant.is_other() => {
+                        let field = if options.is_tuple() {
+                            syn::Index::from(0).into_token_stream()
+                        } else {
+                            options.fields[0].ident.to_token_stream()
+                        };
+                        quote! {
+                            Self::#ident { #field: #TEMP, .. } => ::core::clone::Clone::clone(#TEMP)
+                        }
+                    }
+                    _ => {
+                        let tag_value = variant.tag_value();
+                        quote! { Self::#ident { .. } => #tag_value }
+                    }
+                }
+            });
+
+            quote! {
+                impl #impl_generics #WRITE_TAG for #name #ty_generics #where_clause {
+                    type TagType = #ty;
+
+                    fn tag(&self) -> Self::TagType {
+                        match self {
+                            #(#arms,)*
+                        }
+                    }
+                }
+            }
+        }),
+        _ => None,
+    };
+
     quote! {
         #magic
         #endian
+        #tag
     }
 }
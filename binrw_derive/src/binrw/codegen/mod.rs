@@ -5,7 +5,8 @@ mod write_options;
 
 use crate::{
     binrw::parser::{
-        Assert, AssertionError, CondEndian, Imports, Input, ParseResult, PassedArgs, StructField,
+        Assert, AssertionError, Base, CondEndian, Imports, Input, ParseResult, PassedArgs,
+        StructField,
     },
     named_args::{arg_type_name, derive_from_imports},
     util::{quote_spanned_any, IdentStr},
@@ -13,9 +14,9 @@ use crate::{
 use proc_macro2::{Span, TokenStream};
 use quote::{quote, quote_spanned, ToTokens};
 use sanitization::{
-    ARGS, ARGS_LIFETIME, ARGS_MACRO, ASSERT, ASSERT_ERROR_FN, BINREAD_TRAIT, BINWRITE_TRAIT,
-    BIN_ERROR, BIN_RESULT, ENDIAN_ENUM, OPT, POS, READER, READ_TRAIT, SEEK_TRAIT, TEMP, WRITER,
-    WRITE_TRAIT,
+    ARGS, ARGS_LIFETIME, ARGS_MACRO, ASSERT, ASSERT_ERROR_FN, BASE_POS, BINREAD_TRAIT,
+    BINWRITE_TRAIT, BIN_ERROR, BIN_RESULT, ENDIAN_ENUM, OPT, POS, READER, READ_TRAIT, SEEK_FROM,
+    SEEK_TRAIT, TEMP, WRITER, WRITE_TRAIT,
 };
 use syn::{spanned::Spanned, DeriveInput, Ident, Type};
 
@@ -257,9 +258,52 @@ fn get_destructured_imports(
     }
 }
 
+fn get_base_position(input: &Input, stream: &TokenStream) -> Option<TokenStream> {
+    let base = match input.base()? {
+        // A mapped stream may not share positions with the outer stream, so
+        // the start of the struct has to be taken from the mapped stream
+        Base::Relative if input.map_stream().is_some() => quote! {
+            #SEEK_TRAIT::stream_position(#stream)?
+        },
+        Base::Relative => POS.to_token_stream(),
+        Base::Expr(base) => quote! { (#base) as u64 },
+    };
+
+    Some(quote! {
+        let #BASE_POS: u64 = #base;
+    })
+}
+
+fn map_seek_from(seek: &TokenStream, relative: bool) -> TokenStream {
+    if relative {
+        quote! {
+            match #seek {
+                #SEEK_FROM::Start(pos) => #SEEK_FROM::Start(#BASE_POS + pos),
+                pos => pos,
+            }
+        }
+    } else {
+        seek.clone()
+    }
+}
+
+/// Returns an expression for the number of bytes needed to advance the stream
+/// to the next multiple of `align`. Relative offsets are signed, since an
+/// explicit base may be after the current position.
+fn get_align_padding(stream: &TokenStream, align: &TokenStream, relative: bool) -> TokenStream {
+    let base = relative.then(|| quote! { - (#BASE_POS as i64) });
+    quote! {{
+        let align = (#align) as i64;
+        let pos = #SEEK_TRAIT::stream_position(#stream)? as i64 #base;
+        (align - (pos % align)) % align
+    }}
+}
+
 fn get_endian(endian: &CondEndian) -> TokenStream {
     match endian {
-        CondEndian::Inherited => OPT.to_token_stream(),
+        // A marker is read in the prelude, which then shadows the inherited
+        // endianness
+        CondEndian::Inherited | CondEndian::Marker { .. } => OPT.to_token_stream(),
         CondEndian::Fixed(endian) => endian.to_token_stream(),
         CondEndian::Cond(endian, condition) => {
             let (true_cond, false_cond) = (endian, endian.flipped());
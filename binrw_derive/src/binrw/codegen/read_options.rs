@@ -2,17 +2,17 @@ mod r#enum;
 mod map;
 mod r#struct;
 
-use super::{get_assertions, get_destructured_imports};
+use super::{get_assertions, get_base_position, get_destructured_imports};
 use crate::{
     binrw::{
         codegen::{
             get_endian,
             sanitization::{
-                ARGS, ASSERT_MAGIC, MAP_READER_TYPE_HINT, OPT, POS, READER, RESTORE_POSITION,
-                SEEK_TRAIT,
+                ARGS, ASSERT_MAGIC, MAP_READER_TYPE_HINT, OPT, POS, READER, READ_ENDIAN_MARKER,
+                RESTORE_POSITION, SEEK_TRAIT,
             },
         },
-        parser::{Input, Magic, Map},
+        parser::{CondEndian, Input, Magic, Map},
     },
     util::quote_spanned_any,
 };
@@ -28,7 +28,7 @@ pub(crate) fn generate(input: &Input, derive_input: &syn::DeriveInput) -> TokenS
         Map::None => match input {
             Input::UnitStruct(_) => (generate_unit_struct(input, name, None), false),
             Input::Struct(s) => (generate_struct(input, name, s), true),
-            Input::Enum(e) => (generate_data_enum(input, name, e), false),
+            Input::Enum(e) => (generate_data_enum(input, name, e), e.tag.is_some()),
             Input::UnitOnlyEnum(e) => (
                 generate_unit_enum(input, name, e),
                 e.map.as_repr().is_some(),
@@ -38,6 +38,9 @@ pub(crate) fn generate(input: &Input, derive_input: &syn::DeriveInput) -> TokenS
         Map::Map(map) => (map::generate_map(input, name, map), true),
         Map::Repr(ty) => match input {
             Input::UnitOnlyEnum(e) => (generate_unit_enum(input, name, e), true),
+            Input::Enum(e) if e.discriminant_repr().is_some() => {
+                (generate_data_enum(input, name, e), true)
+            }
             _ => (
                 map::generate_try_map(
                     input,
@@ -111,10 +114,12 @@ impl<'input> PreludeGenerator<'input> {
     fn add_magic_pre_assertion(mut self) -> Self {
         let head = self.out;
         let magic = get_magic(self.input.magic(), &self.reader_var, OPT);
+        let endian_marker = get_endian_marker(self.input.endian(), &self.reader_var);
         let pre_assertions = get_assertions(self.input.pre_assertions());
         self.out = quote! {
             #head
             #magic
+            #endian_marker
             #(#pre_assertions)*
         };
 
@@ -135,6 +140,18 @@ impl<'input> PreludeGenerator<'input> {
         self
     }
 
+    fn add_base_position(mut self) -> Self {
+        if let Some(base) = get_base_position(self.input, &self.reader_var) {
+            let head = self.out;
+            self.out = quote! {
+                #head
+                #base
+            };
+        }
+
+        self
+    }
+
     fn reset_position_after_magic(mut self) -> Self {
         if self.input.magic().is_some() {
             let reader_var = &self.reader_var;
@@ -149,6 +166,16 @@ impl<'input> PreludeGenerator<'input> {
     }
 }
 
+fn get_endian_marker(endian: &CondEndian, reader_var: impl ToTokens) -> Option<TokenStream> {
+    if let CondEndian::Marker { big, little } = endian {
+        Some(quote! {
+            let #OPT = #READ_ENDIAN_MARKER(#reader_var, #big, #little)?;
+        })
+    } else {
+        None
+    }
+}
+
 fn get_magic(
     magic: &Magic,
     reader_var: impl ToTokens,
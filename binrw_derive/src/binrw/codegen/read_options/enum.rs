@@ -7,7 +7,7 @@ use crate::binrw::{
         BACKTRACE_FRAME, BIN_ERROR, ERROR_BASKET, OPT, POS, READER, READ_METHOD,
         RESTORE_POSITION_VARIANT, TEMP, WITH_CONTEXT,
     },
-    parser::{Enum, EnumErrorMode, EnumVariant, Input, UnitEnumField, UnitOnlyEnum},
+    parser::{Enum, EnumErrorMode, EnumVariant, FieldMode, Input, UnitEnumField, UnitOnlyEnum},
 };
 use proc_macro2::TokenStream;
 use quote::quote;
@@ -25,7 +25,7 @@ pub(super) fn generate_unit_enum(
         .finish();
 
     let read = match en.map.as_repr() {
-        Some(repr) => generate_unit_enum_repr(&input.stream_ident_or(READER), repr, &en.fields),
+        Some(repr) => generate_unit_enum_repr(&input.stream_ident_or(READER), repr, en),
         None => generate_unit_enum_magic(&input.stream_ident_or(READER), &en.fields),
     };
 
@@ -38,25 +38,47 @@ pub(super) fn generate_unit_enum(
 fn generate_unit_enum_repr(
     reader_var: &TokenStream,
     repr: &TokenStream,
-    variants: &[UnitEnumField],
+    en: &UnitOnlyEnum,
 ) -> TokenStream {
-    let clauses = variants.iter().map(|variant| {
-        let ident = &variant.ident;
-        let pre_assertions = variant
-            .pre_assertions
-            .iter()
-            .map(|assert| &assert.condition);
+    // Enums with variants holding the value cannot be cast, so the
+    // discriminant values are matched directly instead
+    let other = en.other_variant();
+    let discriminants = en.has_value_variants().then(|| en.discriminants());
 
-        quote! {
-            if #TEMP == Self::#ident as #repr #(&& (#pre_assertions))* {
-                Ok(Self::#ident)
+    let clauses = en
+        .fields
+        .iter()
+        .enumerate()
+        .filter(|(_, variant)| variant.other.is_none())
+        .map(|(index, variant)| {
+            let ident = &variant.ident;
+            let pre_assertions = variant
+                .pre_assertions
+                .iter()
+                .map(|assert| &assert.condition);
+
+            if let Some(pattern) = &variant.pattern {
+                return quote! {
+                    if ::core::matches!(#TEMP, #pattern) #(&& (#pre_assertions))* {
+                        Ok(Self::#ident(#TEMP))
+                    }
+                };
             }
-        }
-    });
 
-    quote! {
-        let #TEMP: #repr = #READ_METHOD(#reader_var, #OPT, ())?;
-        #(#clauses else)* {
+            let value = discriminants.as_ref().map_or_else(
+                || quote! { Self::#ident as #repr },
+                |discriminants| discriminants[index].clone(),
+            );
+
+            quote! {
+                if #TEMP == #value #(&& (#pre_assertions))* {
+                    Ok(Self::#ident)
+                }
+            }
+        });
+
+    let no_match = quote! {
+        {
             Err(#WITH_CONTEXT(
                 #BIN_ERROR::NoVariantMatch {
                     pos: #POS,
@@ -67,6 +89,32 @@ fn generate_unit_enum_repr(
                 })
             ))
         }
+    };
+
+    let fallback = match other {
+        Some(other) if other.pre_assertions.is_empty() => {
+            let ident = &other.ident;
+            quote! {
+                {
+                    Ok(Self::#ident(#TEMP))
+                }
+            }
+        }
+        Some(other) => {
+            let ident = &other.ident;
+            let pre_assertions = other.pre_assertions.iter().map(|assert| &assert.condition);
+            quote! {
+                if #((#pre_assertions))&&* {
+                    Ok(Self::#ident(#TEMP))
+                } else #no_match
+            }
+        }
+        None => no_match,
+    };
+
+    quote! {
+        let #TEMP: #repr = #READ_METHOD(#reader_var, #OPT, ())?;
+        #(#clauses else)* #fallback
     }
 }
 
@@ -147,6 +195,36 @@ fn generate_unit_enum_magic(reader_var: &TokenStream, variants: &[UnitEnumField]
 }
 
 pub(super) fn generate_data_enum(input: &Input, name: Option<&Ident>, en: &Enum) -> TokenStream {
+    if let Some(tag) = &en.tag {
+        let tag_values = en
+            .variants
+            .iter()
+            .map(|variant| variant.tag_value().cloned().unwrap_or_default())
+            .collect::<Vec<_>>();
+        return generate_tagged_data_enum(
+            input,
+            name,
+            en,
+            tag,
+            en.tag_type.as_ref(),
+            &tag_values,
+            "Unexpected tag value for enum: {:?}",
+        );
+    }
+
+    if let Some(repr) = en.discriminant_repr() {
+        let reader_var = input.stream_ident_or(READER);
+        return generate_tagged_data_enum(
+            input,
+            name,
+            en,
+            &quote! { #READ_METHOD(#reader_var, #OPT, ())? },
+            Some(repr),
+            &en.discriminants(),
+            "Unexpected value for enum: {:?}",
+        );
+    }
+
     let return_all_errors = en.error_mode != EnumErrorMode::ReturnUnexpectedError;
 
     let (create_error_basket, return_error) = if return_all_errors {
@@ -181,8 +259,18 @@ pub(super) fn generate_data_enum(input: &Input, name: Option<&Ident>, en: &Enum)
         .finish();
 
     let reader_var = input.stream_ident_or(READER);
+    let magic_dispatch = en
+        .is_magic_dispatch()
+        .then(|| generate_magic_dispatch(&reader_var, en));
+
+    // The catch-all variant is only tried once all other variants fail
+    let variants = en
+        .variants
+        .iter()
+        .filter(|variant| !variant.is_other())
+        .chain(en.other_variant());
 
-    let try_each_variant = en.variants.iter().map(|variant| {
+    let try_each_variant = variants.map(|variant| {
         let body = generate_variant_impl(en, variant);
 
         let handle_error = if return_all_errors {
@@ -210,17 +298,146 @@ pub(super) fn generate_data_enum(input: &Input, name: Option<&Ident>, en: &Enum)
 
     quote! {
         #prelude
+        #magic_dispatch
         #create_error_basket
         #(#try_each_variant)*
         #return_error
     }
 }
 
+fn generate_tagged_data_enum(
+    input: &Input,
+    name: Option<&Ident>,
+    en: &Enum,
+    tag: &TokenStream,
+    tag_type: Option<&TokenStream>,
+    tag_values: &[TokenStream],
+    unmatched_message: &str,
+) -> TokenStream {
+    let prelude = PreludeGenerator::new(input)
+        .add_imports(name)
+        .add_endian()
+        .add_magic_pre_assertion()
+        .finish();
+
+    let tag_type = tag_type.map(|ty| quote! { : #ty });
+
+    let clauses = en
+        .variants
+        .iter()
+        .zip(tag_values)
+        .filter(|(variant, _)| !variant.is_other())
+        .map(|(variant, tag_value)| {
+            let body = generate_variant_impl(en, variant);
+
+            quote! {
+                if #TEMP == (#tag_value) {
+                    (|| {
+                        #body
+                    })()
+                }
+            }
+        });
+
+    let fallback = if let Some(other) = en.other_variant() {
+        let body = generate_variant_impl(en, &with_tag_field(other));
+        quote! {
+            (|| {
+                #body
+            })()
+        }
+    } else {
+        quote! {
+            Err(#WITH_CONTEXT(
+                #BIN_ERROR::NoVariantMatch {
+                    pos: #POS,
+                },
+                #BACKTRACE_FRAME::Message({
+                    extern crate alloc;
+                    alloc::format!(#unmatched_message, #TEMP).into()
+                })
+            ))
+        }
+    };
+
+    quote! {
+        #prelude
+        let #TEMP #tag_type = #tag;
+        #(#clauses else)* {
+            #fallback
+        }
+    }
+}
+
+/// Reads the magic once and parses the variant it selects. If this fails, the
+/// position is restored and the variants are tried in turn, so that errors are
+/// reported the same way as for any other enum.
+fn generate_magic_dispatch(reader_var: &TokenStream, en: &Enum) -> TokenStream {
+    let magics = en
+        .variants
+        .iter()
+        .filter_map(|variant| variant.magic().as_ref())
+        .collect::<Vec<_>>();
+    let magic_type = TokenStream::from(magics[0].kind());
+    let amp = magics[0].add_ref();
+
+    let arms = en.variants.iter().zip(&magics).map(|(variant, magic)| {
+        let magic = magic.match_value();
+        let body = generate_variant_impl(en, &without_magic(variant));
+
+        quote! {
+            #magic => (|| {
+                #body
+            })()
+        }
+    });
+
+    quote! {
+        match (|| {
+            let #TEMP: #magic_type = #READ_METHOD(#reader_var, #OPT, ())?;
+            match #amp #TEMP {
+                #(#arms,)*
+                _ => Err(#BIN_ERROR::NoVariantMatch { pos: #POS }),
+            }
+        })() {
+            ok @ Ok(_) => return ok,
+            Err(error) => {
+                #RESTORE_POSITION_VARIANT(#reader_var, #POS, error)?;
+            }
+        }
+    }
+}
+
+/// Returns a copy of the variant without its magic, which has already been
+/// read.
+fn without_magic(variant: &EnumVariant) -> EnumVariant {
+    let mut variant = variant.clone();
+    match &mut variant {
+        EnumVariant::Variant { options, .. } => options.magic = None,
+        EnumVariant::Unit(field) => field.magic = None,
+    }
+    variant
+}
+
+/// Returns a copy of the catch-all variant where the first field receives the
+/// tag instead of being read.
+fn with_tag_field(variant: &EnumVariant) -> EnumVariant {
+    let mut variant = variant.clone();
+    if let EnumVariant::Variant { options, .. } = &mut variant {
+        if let Some(field) = options.fields.first_mut() {
+            field.field_mode = FieldMode::Calc(quote! {
+                ::core::clone::Clone::clone(&#TEMP)
+            });
+        }
+    }
+    variant
+}
+
 fn generate_variant_impl(en: &Enum, variant: &EnumVariant) -> TokenStream {
     let input = Input::Struct(variant.clone().into());
 
     match variant {
-        EnumVariant::Variant { ident, options } => StructGenerator::new(&input, options)
+        EnumVariant::Variant { ident, options, .. } => StructGenerator::new(&input, options)
             .read_fields(
                 None,
                 Some(&format!("{}::{}", en.ident.as_ref().unwrap(), &ident)),
@@ -5,15 +5,16 @@ use crate::binrw::parser::Assert;
 use crate::{
     binrw::{
         codegen::{
-            get_assertions, get_endian, get_map_err, get_passed_args, get_try_calc,
+            get_align_padding, get_assertions, get_endian, get_map_err, get_passed_args,
+            get_try_calc, map_seek_from,
             sanitization::{
                 make_ident, ARGS_TYPE_HINT, BACKTRACE_FRAME, BINREAD_TRAIT, COERCE_FN,
                 DBG_EPRINTLN, MAP_ARGS_TYPE_HINT, MAP_READER_TYPE_HINT, OPT, PARSE_FN_TYPE_HINT,
                 POS, READER, READ_FUNCTION, READ_METHOD, REQUIRED_ARG_TRAIT, SAVED_POSITION,
-                SEEK_FROM, SEEK_TRAIT, TEMP, THIS, WITH_CONTEXT,
+                SEEK_FROM, SEEK_TRAIT, TAKE_READ, TEMP, THIS, WITH_CONTEXT,
             },
         },
-        parser::{ErrContext, FieldMode, Input, Map, Struct, StructField},
+        parser::{ErrContext, FieldMode, Input, Map, Struct, StructField, Take},
     },
     util::quote_spanned_any,
 };
@@ -155,6 +156,7 @@ fn generate_field(
     FieldGenerator::new(input, field)
         .read_value()
         .wrap_map_stream()
+        .wrap_take()
         .try_conversion(name, variant_name)
         .map_value()
         .wrap_debug()
@@ -172,6 +174,7 @@ fn generate_field(
 
 struct FieldGenerator<'field> {
     field: &'field StructField,
+    relative: bool,
     out: TokenStream,
     outer_reader_var: TokenStream,
     reader_var: TokenStream,
@@ -185,6 +188,7 @@ impl<'field> FieldGenerator<'field> {
 
         Self {
             field,
+            relative: input.base().is_some(),
             out: TokenStream::new(),
             outer_reader_var: input.stream_ident_or(READER),
             reader_var,
@@ -357,6 +361,19 @@ impl<'field> FieldGenerator<'field> {
         self
     }
 
+    fn wrap_take(mut self) -> Self {
+        if let Some(Take { len, exact }) = &self.field.take {
+            let rest = self.out;
+            let reader_var = &self.reader_var;
+            let outer_reader_var = &self.outer_reader_var;
+            self.out = quote! {
+                #TAKE_READ(&mut *#outer_reader_var, (#len) as u64, #exact, |#reader_var| #rest)
+            };
+        }
+
+        self
+    }
+
     fn prefix_read_function(mut self) -> Self {
         let read_function = match &self.field.field_mode {
             FieldMode::Function(parser) => {
@@ -524,8 +541,8 @@ impl<'field> FieldGenerator<'field> {
     }
 
     fn wrap_seek(mut self) -> Self {
-        let seek_before = generate_seek_before(&self.outer_reader_var, self.field);
-        let seek_after = generate_seek_after(&self.outer_reader_var, self.field);
+        let seek_before = generate_seek_before(&self.outer_reader_var, self.field, self.relative);
+        let seek_after = generate_seek_after(&self.outer_reader_var, self.field, self.relative);
         if !seek_before.is_empty() || !seek_after.is_empty() {
             let value = self.out;
             self.out = quote! {{
@@ -603,10 +620,15 @@ fn get_prelude(input: &Input, name: Option<&Ident>) -> TokenStream {
         .add_endian()
         .add_magic_pre_assertion()
         .add_map_stream()
+        .add_base_position()
         .finish()
 }
 
-fn generate_seek_after(reader_var: &TokenStream, field: &StructField) -> TokenStream {
+fn generate_seek_after(
+    reader_var: &TokenStream,
+    field: &StructField,
+    relative: bool,
+) -> TokenStream {
     let pad_size_to = field.pad_size_to.as_ref().map(|pad| {
         quote! {{
             let pad = (#pad) as i64;
@@ -623,7 +645,7 @@ fn generate_seek_after(reader_var: &TokenStream, field: &StructField) -> TokenSt
     let align_after = field
         .align_after
         .as_ref()
-        .map(|value| map_align(reader_var, value));
+        .map(|value| map_align(reader_var, value, relative));
 
     quote! {
         #pad_size_to
@@ -632,8 +654,13 @@ fn generate_seek_after(reader_var: &TokenStream, field: &StructField) -> TokenSt
     }
 }
 
-fn generate_seek_before(reader_var: &TokenStream, field: &StructField) -> TokenStream {
+fn generate_seek_before(
+    reader_var: &TokenStream,
+    field: &StructField,
+    relative: bool,
+) -> TokenStream {
     let seek_before = field.seek_before.as_ref().map(|seek| {
+        let seek = map_seek_from(seek, relative);
         quote! {
             #SEEK_TRAIT::seek(#reader_var, #seek)?;
         }
@@ -645,7 +672,7 @@ fn generate_seek_before(reader_var: &TokenStream, field: &StructField) -> TokenS
     let align_before = field
         .align_before
         .as_ref()
-        .map(|value| map_align(reader_var, value));
+        .map(|value| map_align(reader_var, value, relative));
     let pad_size_to_before = field.pad_size_to.as_ref().map(|_| {
         quote! {
             let #POS = #SEEK_TRAIT::stream_position(#reader_var)?;
@@ -668,7 +695,7 @@ fn make_field_vars(
     input: &Input,
     field: &StructField,
 ) -> (TokenStream, TokenStream, Option<Ident>) {
-    let reader_var = if field.map_stream.is_some() {
+    let reader_var = if field.map_stream.is_some() || field.take.is_some() {
         make_ident(&field.ident, "reader").into_token_stream()
     } else {
         input.stream_ident_or(READER)
@@ -689,12 +716,14 @@ fn make_field_vars(
     (reader_var, endian_var, args_var)
 }
 
-fn map_align(reader_var: &TokenStream, align: &TokenStream) -> TokenStream {
-    quote! {{
-        let align = (#align) as i64;
-        let pos = #SEEK_TRAIT::stream_position(#reader_var)? as i64;
-        #SEEK_TRAIT::seek(#reader_var, #SEEK_FROM::Current((align - (pos % align)) % align))?;
-    }}
+fn map_align(reader_var: &TokenStream, align: &TokenStream, relative: bool) -> TokenStream {
+    let padding = get_align_padding(reader_var, align, relative);
+    quote! {
+        {
+            let padding = #padding;
+            #SEEK_TRAIT::seek(#reader_var, #SEEK_FROM::Current(padding))?;
+        }
+    }
 }
 
 fn map_pad(reader_var: &TokenStream, pad: &TokenStream) -> TokenStream {
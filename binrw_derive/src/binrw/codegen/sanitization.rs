@@ -41,6 +41,8 @@ ident_str! {
     pub(crate) SAVED_POSITION = "__binrw_generated_saved_position";
     pub(crate) ASSERT_MAGIC = from_crate!(__private::magic);
     pub(crate) ASSERT = from_crate!(__private::assert);
+    pub(crate) READ_ENDIAN_MARKER = from_crate!(__private::read_endian_marker);
+    pub(crate) WRITE_ENDIAN_MARKER = from_crate!(__private::write_endian_marker);
     pub(crate) ASSERT_ERROR_FN = from_crate!(__private::AssertErrorFn);
     pub(crate) COERCE_FN = from_crate!(__private::coerce_fn);
     pub(crate) ARGS_TYPE_HINT = from_crate!(__private::parse_function_args_type_hint);
@@ -58,6 +60,8 @@ ident_str! {
     pub(crate) WRITE_FN_TRY_MAP_OUTPUT_TYPE_HINT = from_crate!(__private::write_fn_try_map_output_type_hint);
     pub(crate) RESTORE_POSITION = from_crate!(__private::restore_position);
     pub(crate) RESTORE_POSITION_VARIANT = from_crate!(__private::restore_position_variant);
+    pub(crate) TAKE_READ = from_crate!(__private::take_read);
+    pub(crate) TAKE_WRITE = from_crate!(__private::take_write);
     pub(crate) WRITE_ZEROES = from_crate!(__private::write_zeroes);
     pub(crate) ARGS_MACRO = from_crate!(args);
     pub(crate) META_ENDIAN_KIND = from_crate!(meta::EndianKind);
@@ -65,6 +69,7 @@ ident_str! {
     pub(crate) READ_MAGIC = from_crate!(meta::ReadMagic);
     pub(crate) WRITE_ENDIAN = from_crate!(meta::WriteEndian);
     pub(crate) WRITE_MAGIC = from_crate!(meta::WriteMagic);
+    pub(crate) WRITE_TAG = from_crate!(meta::WriteTag);
     pub(crate) WITH_CONTEXT = from_crate!(error::ContextExt::with_context);
     pub(crate) BACKTRACE_FRAME = from_crate!(error::BacktraceFrame);
     pub(crate) TEMP = "__binrw_temp";
@@ -74,6 +79,7 @@ ident_str! {
     pub(crate) READ_FUNCTION = "__binrw_generated_read_function";
     pub(crate) WRITE_FUNCTION = "__binrw_generated_write_function";
     pub(crate) BEFORE_POS = "__binrw_generated_before_pos";
+    pub(crate) BASE_POS = "__binrw_generated_base_pos";
     pub(crate) DBG_EPRINTLN = from_crate!(__private::eprintln);
 }
 
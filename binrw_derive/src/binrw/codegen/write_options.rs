@@ -25,6 +25,7 @@ pub(crate) fn generate(input: &Input, derive_input: &syn::DeriveInput) -> TokenS
         Map::Try(map) | Map::Map(map) => generate_map(input, name, map),
         Map::Repr(map) => match input {
             Input::UnitOnlyEnum(e) => generate_unit_enum(input, name, e),
+            Input::Enum(e) if e.discriminant_repr().is_some() => generate_data_enum(input, name, e),
             _ => generate_map(input, name, map),
         },
     };
@@ -1,7 +1,7 @@
 use super::{prelude::PreludeGenerator, r#struct::StructGenerator};
 use crate::binrw::{
-    codegen::sanitization::{OPT, WRITER, WRITE_METHOD},
-    parser::{Enum, EnumVariant, Input, UnitEnumField, UnitOnlyEnum},
+    codegen::sanitization::{BIN_ERROR, OPT, SEEK_TRAIT, TEMP, WRITER, WRITE_METHOD},
+    parser::{Enum, EnumVariant, FieldMode, Input, UnitEnumField, UnitOnlyEnum},
 };
 use proc_macro2::{Ident, TokenStream};
 use quote::quote;
@@ -13,7 +13,7 @@ pub(crate) fn generate_unit_enum(
 ) -> TokenStream {
     let writer_var = input.stream_ident_or(WRITER);
     let write = match en.map.as_repr() {
-        Some(repr) => generate_unit_enum_repr(&writer_var, repr, &en.fields),
+        Some(repr) => generate_unit_enum_repr(&writer_var, repr, en),
         None => generate_unit_enum_magic(&writer_var, &en.fields),
     };
 
@@ -58,13 +58,35 @@ impl<'a> EnumGenerator<'a> {
     }
 
     fn write_variants(mut self) -> Self {
-        let variants = self.en.variants.iter().map(|variant| {
+        let discriminants = self
+            .en
+            .discriminant_repr()
+            .map(|repr| (repr, self.en.discriminants()));
+
+        // The tag of a catch-all variant is written by the enum (or by its
+        // parent) instead of by the variant itself
+        let tag_from_field = self.en.tag_type.is_some() || discriminants.is_some();
+
+        let variants = self.en.variants.iter().enumerate().map(|(index, variant)| {
             let name = variant.ident();
             let fields = match variant {
                 EnumVariant::Variant { options, .. } => Some(options.fields_pattern()),
                 EnumVariant::Unit(_) => None,
             };
 
+            let tag_field = match variant {
+                EnumVariant::Variant { options, .. } if tag_from_field && variant.is_other() => {
+                    options.fields.first().map(|field| field.ident.clone())
+                }
+                _ => None,
+            };
+
+            let variant = &if tag_field.is_some() {
+                without_tag_field(variant)
+            } else {
+                variant.clone()
+            };
+
             let writer_var = &self.writer_var;
             let writing = match variant {
                 EnumVariant::Variant { options, .. } => {
@@ -92,8 +114,25 @@ impl<'a> EnumGenerator<'a> {
                     .unwrap_or_default(),
             };
 
+            let discriminant = discriminants.as_ref().map(|(repr, values)| {
+                let value = tag_field.as_ref().map_or_else(
+                    || values[index].clone(),
+                    |ident| quote! { ::core::clone::Clone::clone(#ident) },
+                );
+                quote! {
+                    let #TEMP: #repr = #value;
+                    #WRITE_METHOD (
+                        &#TEMP,
+                        #writer_var,
+                        #OPT,
+                        ()
+                    )?;
+                }
+            });
+
             quote! {
                 Self::#name #fields => {
+                    #discriminant
                     #writing
                 }
             }
@@ -127,12 +166,73 @@ impl<'a> EnumGenerator<'a> {
     }
 }
 
+/// Returns a copy of the catch-all variant where the first field, which holds
+/// the tag, is not written.
+fn without_tag_field(variant: &EnumVariant) -> EnumVariant {
+    let mut variant = variant.clone();
+    if let EnumVariant::Variant { options, .. } = &mut variant {
+        if let Some(field) = options.fields.first_mut() {
+            field.field_mode = FieldMode::Default;
+        }
+    }
+    variant
+}
+
 fn generate_unit_enum_repr(
     writer_var: &TokenStream,
     repr: &TokenStream,
-    variants: &[UnitEnumField],
+    en: &UnitOnlyEnum,
 ) -> TokenStream {
-    let branches = variants.iter().map(|variant| {
+    // Enums with variants holding the value cannot be cast, so the
+    // discriminant values are written directly instead
+    if en.has_value_variants() {
+        let branches = en
+            .fields
+            .iter()
+            .zip(en.discriminants())
+            .map(|(variant, value)| {
+                let name = &variant.ident;
+                if let Some(pattern) = &variant.pattern {
+                    // The value must read back as the same variant
+                    let message =
+                        format!("value {{:?}} does not match the pattern of variant `{name}`");
+                    quote! {
+                        Self::#name(#TEMP) => {
+                            if !::core::matches!(*#TEMP, #pattern) {
+                                extern crate alloc;
+                                return Err(#BIN_ERROR::AssertFail {
+                                    pos: #SEEK_TRAIT::stream_position(#writer_var)?,
+                                    message: alloc::format!(#message, #TEMP),
+                                });
+                            }
+                            *#TEMP
+                        }
+                    }
+                } else if variant.has_value_field {
+                    quote! {
+                        Self::#name(#TEMP) => *#TEMP
+                    }
+                } else {
+                    quote! {
+                        Self::#name => #value
+                    }
+                }
+            });
+
+        return quote! {
+            let #TEMP: #repr = match self {
+                #(#branches),*
+            };
+            #WRITE_METHOD (
+                &#TEMP,
+                #writer_var,
+                #OPT,
+                (),
+            )?;
+        };
+    }
+
+    let branches = en.fields.iter().map(|variant| {
         let name = &variant.ident;
         quote! {
             Self::#name => Self::#name
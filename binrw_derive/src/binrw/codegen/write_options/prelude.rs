@@ -1,8 +1,10 @@
 use crate::{
     binrw::{
         codegen::{
-            get_assertions, get_destructured_imports, get_endian,
-            sanitization::{ARGS, MAP_WRITER_TYPE_HINT, OPT, WRITER, WRITE_METHOD},
+            get_assertions, get_base_position, get_destructured_imports, get_endian,
+            sanitization::{
+                ARGS, MAP_WRITER_TYPE_HINT, OPT, WRITER, WRITE_ENDIAN_MARKER, WRITE_METHOD,
+            },
         },
         parser::{CondEndian, Input, Magic},
     },
@@ -77,6 +79,19 @@ impl<'a> PreludeGenerator<'a> {
         self
     }
 
+    pub(crate) fn prefix_endian_marker(mut self, endian: &CondEndian) -> Self {
+        if let CondEndian::Marker { big, little } = endian {
+            let writer_var = &self.writer_var;
+            let out = self.out;
+            self.out = quote! {
+                #WRITE_ENDIAN_MARKER(#writer_var, #OPT, #big, #little)?;
+                #out
+            };
+        }
+
+        self
+    }
+
     pub(crate) fn prefix_endian(mut self, endian: &CondEndian) -> Self {
         let endian = get_endian(endian);
         let out = self.out;
@@ -88,6 +103,18 @@ impl<'a> PreludeGenerator<'a> {
         self
     }
 
+    pub(crate) fn prefix_base_position(mut self) -> Self {
+        if let Some(base) = get_base_position(self.input, self.writer_var) {
+            let out = self.out;
+            self.out = quote! {
+                #base
+                #out
+            };
+        }
+
+        self
+    }
+
     pub(crate) fn prefix_map_stream(mut self) -> Self {
         if let Some(map_stream) = self.input.map_stream() {
             let outer_writer = self.input.stream_ident_or(WRITER);
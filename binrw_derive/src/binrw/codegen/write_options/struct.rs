@@ -50,7 +50,9 @@ impl<'input> StructGenerator<'input> {
 
     pub(super) fn prefix_prelude(mut self) -> Self {
         self.out = PreludeGenerator::new(self.out, self.input, self.name, self.writer_var)
+            .prefix_base_position()
             .prefix_map_stream()
+            .prefix_endian_marker(&self.st.endian)
             .prefix_magic(&self.st.magic)
             .prefix_endian(&self.st.endian)
             .prefix_assertions()
@@ -60,11 +62,12 @@ impl<'input> StructGenerator<'input> {
     }
 
     pub(super) fn write_fields(mut self) -> Self {
+        let relative = self.input.base().is_some();
         let write_fields = self
             .st
             .fields
             .iter()
-            .map(|field| write_field(self.writer_var, field));
+            .map(|field| write_field(self.writer_var, field, relative));
 
         self.out = quote! {
             #(#write_fields)*
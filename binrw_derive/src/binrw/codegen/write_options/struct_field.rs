@@ -1,17 +1,18 @@
 use crate::{
     binrw::{
         codegen::{
-            get_assertions, get_endian, get_map_err, get_passed_args, get_try_calc,
+            get_align_padding, get_assertions, get_endian, get_map_err, get_passed_args,
+            get_try_calc, map_seek_from,
             sanitization::{
                 make_ident, BEFORE_POS, BINWRITE_TRAIT, MAP_WRITER_TYPE_HINT, POS,
-                REQUIRED_ARG_TRAIT, SAVED_POSITION, SEEK_FROM, SEEK_TRAIT, WRITE_ARGS_TYPE_HINT,
-                WRITE_FN_MAP_OUTPUT_TYPE_HINT, WRITE_FN_TRY_MAP_OUTPUT_TYPE_HINT,
-                WRITE_FN_TYPE_HINT, WRITE_FUNCTION, WRITE_MAP_ARGS_TYPE_HINT,
-                WRITE_MAP_INPUT_TYPE_HINT, WRITE_METHOD, WRITE_TRY_MAP_ARGS_TYPE_HINT,
-                WRITE_ZEROES,
+                REQUIRED_ARG_TRAIT, SAVED_POSITION, SEEK_FROM, SEEK_TRAIT, TAKE_WRITE,
+                WRITE_ARGS_TYPE_HINT, WRITE_FN_MAP_OUTPUT_TYPE_HINT,
+                WRITE_FN_TRY_MAP_OUTPUT_TYPE_HINT, WRITE_FN_TYPE_HINT, WRITE_FUNCTION,
+                WRITE_MAP_ARGS_TYPE_HINT, WRITE_MAP_INPUT_TYPE_HINT, WRITE_METHOD,
+                WRITE_TRY_MAP_ARGS_TYPE_HINT, WRITE_ZEROES,
             },
         },
-        parser::{FieldMode, Map, StructField},
+        parser::{FieldMode, Map, StructField, Take},
     },
     util::quote_spanned_any,
 };
@@ -21,10 +22,15 @@ use proc_macro2::TokenStream;
 use quote::{quote, quote_spanned, ToTokens};
 use syn::{spanned::Spanned, Ident};
 
-pub(crate) fn write_field(writer_var: &TokenStream, field: &StructField) -> TokenStream {
-    StructFieldGenerator::new(field, writer_var)
+pub(crate) fn write_field(
+    writer_var: &TokenStream,
+    field: &StructField,
+    relative: bool,
+) -> TokenStream {
+    StructFieldGenerator::new(field, writer_var, relative)
         .write_field()
         .wrap_map_stream()
+        .wrap_take()
         .prefix_map_value()
         .prefix_calc_value()
         .wrap_padding()
@@ -39,17 +45,19 @@ pub(crate) fn write_field(writer_var: &TokenStream, field: &StructField) -> Toke
 
 struct StructFieldGenerator<'input> {
     field: &'input StructField,
+    relative: bool,
     outer_writer_var: &'input TokenStream,
     writer_var: Cow<'input, TokenStream>,
     out: TokenStream,
 }
 
 impl<'a> StructFieldGenerator<'a> {
-    fn new(field: &'a StructField, outer_writer_var: &'a TokenStream) -> Self {
+    fn new(field: &'a StructField, outer_writer_var: &'a TokenStream, relative: bool) -> Self {
         Self {
             field,
+            relative,
             outer_writer_var,
-            writer_var: if field.map_stream.is_some() {
+            writer_var: if field.map_stream.is_some() || field.take.is_some() {
                 Cow::Owned(make_ident(&field.ident, "reader").into_token_stream())
             } else {
                 Cow::Borrowed(outer_writer_var)
@@ -84,6 +92,22 @@ impl<'a> StructFieldGenerator<'a> {
         self
     }
 
+    fn wrap_take(mut self) -> Self {
+        if let Some(Take { len, exact }) = &self.field.take {
+            let rest = self.out;
+            let writer_var = &self.writer_var;
+            let outer_writer_var = self.outer_writer_var;
+            self.out = quote! {
+                #TAKE_WRITE(#outer_writer_var, (#len) as u64, #exact, |#writer_var| {
+                    #rest
+                    Ok(())
+                })?;
+            };
+        }
+
+        self
+    }
+
     fn prefix_write_function(mut self) -> Self {
         if !self.field.is_written() {
             return self;
@@ -245,8 +269,8 @@ impl<'a> StructFieldGenerator<'a> {
     fn wrap_padding(mut self) -> Self {
         let out = self.out;
 
-        let pad_before = pad_before(self.outer_writer_var, self.field);
-        let pad_after = pad_after(self.outer_writer_var, self.field);
+        let pad_before = pad_before(self.outer_writer_var, self.field, self.relative);
+        let pad_after = pad_after(self.outer_writer_var, self.field, self.relative);
         self.out = quote! {
             #pad_before
             #out
@@ -350,7 +374,7 @@ fn map_func_ident(ident: &Ident) -> Ident {
     make_ident(ident, "map_func")
 }
 
-fn pad_after(writer_var: &TokenStream, field: &StructField) -> TokenStream {
+fn pad_after(writer_var: &TokenStream, field: &StructField, relative: bool) -> TokenStream {
     let pad_size_to = field.pad_size_to.as_ref().map(|size| {
         quote! {{
             let pad_to_size = (#size) as u64;
@@ -368,14 +392,13 @@ fn pad_after(writer_var: &TokenStream, field: &StructField) -> TokenStream {
         }
     });
     let align_after = field.align_after.as_ref().map(|alignment| {
-        quote! {{
-            let pos = #SEEK_TRAIT::stream_position(#writer_var)?;
-            let align = ((#alignment) as u64);
-            let rem = pos % align;
-            if rem != 0 {
-                #WRITE_ZEROES(#writer_var, align - rem)?;
+        let padding = get_align_padding(writer_var, alignment, relative);
+        quote! {
+            {
+                let padding = #padding;
+                #WRITE_ZEROES(#writer_var, padding as u64)?;
             }
-        }}
+        }
     });
     let restore_position = field.restore_position.map(|()| {
         quote! {
@@ -391,8 +414,9 @@ fn pad_after(writer_var: &TokenStream, field: &StructField) -> TokenStream {
     }
 }
 
-fn pad_before(writer_var: &TokenStream, field: &StructField) -> TokenStream {
+fn pad_before(writer_var: &TokenStream, field: &StructField, relative: bool) -> TokenStream {
     let seek_before = field.seek_before.as_ref().map(|seek| {
+        let seek = map_seek_from(seek, relative);
         quote! {
             #SEEK_TRAIT::seek(
                 #writer_var,
@@ -406,14 +430,13 @@ fn pad_before(writer_var: &TokenStream, field: &StructField) -> TokenStream {
         }
     });
     let align_before = field.align_before.as_ref().map(|alignment| {
-        quote! {{
-            let pos = #SEEK_TRAIT::stream_position(#writer_var)?;
-            let align = ((#alignment) as u64);
-            let rem = pos % align;
-            if rem != 0 {
-                #WRITE_ZEROES(#writer_var, align - rem)?;
+        let padding = get_align_padding(writer_var, alignment, relative);
+        quote! {
+            {
+                let padding = #padding;
+                #WRITE_ZEROES(#writer_var, padding as u64)?;
             }
-        }}
+        }
     });
     let pad_size_to_before = field.pad_size_to.as_ref().map(|_| {
         quote! {
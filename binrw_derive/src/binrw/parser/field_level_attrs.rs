@@ -1,11 +1,12 @@
 use super::{
     attr_struct,
     top_level_attrs::StructAttr,
-    types::{Assert, CondEndian, Condition, ErrContext, FieldMode, Magic, Map, PassedArgs},
+    types::{Assert, CondEndian, Condition, ErrContext, FieldMode, Magic, Map, PassedArgs, Take},
     FromAttrs, FromField, FromInput, ParseResult, SpannedValue, Struct, TrySet,
 };
 use crate::{binrw::Options, combine_error};
 use proc_macro2::TokenStream;
+use quote::ToTokens;
 use syn::spanned::Spanned;
 
 attr_struct! {
@@ -56,6 +57,8 @@ attr_struct! {
         pub(crate) seek_before: Option<TokenStream>,
         #[from(RW:PadSizeTo)]
         pub(crate) pad_size_to: Option<TokenStream>,
+        #[from(RW:Take, RW:TakeExact)]
+        pub(crate) take: Option<Take>,
         #[from(RO:Debug)] // TODO is this really RO?
         pub(crate) debug: Option<()>,
     }
@@ -128,6 +131,7 @@ impl StructField {
                 align_after,
                 seek_before,
                 pad_size_to,
+                take,
                 magic
             )
     }
@@ -142,7 +146,7 @@ impl StructField {
         self.temp = Some(());
     }
 
-    fn validate(&self, _: Options) -> syn::Result<()> {
+    fn validate(&self, options: Options) -> syn::Result<()> {
         let mut all_errors = None::<syn::Error>;
 
         if self.do_try.is_some() && self.generated_value() {
@@ -170,6 +174,33 @@ impl StructField {
             );
         }
 
+        if let Some(take) = &self.take {
+            if self.map_stream.is_some() {
+                combine_error(
+                    &mut all_errors,
+                    syn::Error::new(
+                        take.len.span(),
+                        "`take` and `map_stream` are mutually exclusive",
+                    ),
+                );
+            }
+
+            if options.write && !self.is_written() {
+                combine_error(
+                    &mut all_errors,
+                    syn::Error::new(take.len.span(), "`take` is incompatible with `ignore`"),
+                );
+            } else if !options.write && self.generated_value() {
+                combine_error(
+                    &mut all_errors,
+                    syn::Error::new(
+                        take.len.span(),
+                        "`take` is incompatible with `default`, `calc`, and `try_calc`",
+                    ),
+                );
+            }
+        }
+
         if self.has_named_arg_directives()
             && !matches!(self.args, PassedArgs::None | PassedArgs::Named(..))
         {
@@ -238,6 +269,7 @@ impl FromField for StructField {
             align_after: <_>::default(),
             seek_before: <_>::default(),
             pad_size_to: <_>::default(),
+            take: <_>::default(),
             #[cfg(feature = "verbose-backtrace")]
             keyword_spans: <_>::default(),
             err_context: <_>::default(),
@@ -274,10 +306,18 @@ attr_struct! {
     #[derive(Clone, Debug)]
     pub(crate) struct UnitEnumField {
         pub(crate) ident: syn::Ident,
+        pub(crate) discriminant: Option<TokenStream>,
+        pub(crate) has_value_field: bool,
         #[from(RW:Magic)]
         pub(crate) magic: Magic,
         #[from(RO:PreAssert)]
         pub(crate) pre_assertions: Vec<Assert>,
+        #[from(RW:TagValue)]
+        pub(crate) tag_value: Option<TokenStream>,
+        #[from(RW:Other)]
+        pub(crate) other: Option<()>,
+        #[from(RW:Pattern, RW:MagicRange)]
+        pub(crate) pattern: Option<TokenStream>,
     }
 }
 
@@ -286,6 +326,7 @@ impl From<UnitEnumField> for Struct {
         Self {
             magic: value.magic,
             pre_assertions: value.pre_assertions,
+            tag_value: value.tag_value,
             ..<_>::default()
         }
     }
@@ -297,8 +338,19 @@ impl FromField for UnitEnumField {
     fn from_field(field: &Self::In, _: usize, options: Options) -> ParseResult<Self> {
         let this = Self {
             ident: field.ident.clone(),
+            discriminant: field
+                .discriminant
+                .as_ref()
+                .map(|(_, expr)| expr.to_token_stream()),
+            has_value_field: matches!(
+                &field.fields,
+                syn::Fields::Unnamed(fields) if fields.unnamed.len() == 1
+            ),
             magic: <_>::default(),
             pre_assertions: <_>::default(),
+            tag_value: <_>::default(),
+            other: <_>::default(),
+            pattern: <_>::default(),
             #[cfg(feature = "verbose-backtrace")]
             keyword_spans: <_>::default(),
         };
@@ -323,6 +375,7 @@ impl FromField for UnitEnumField {
 pub(crate) enum EnumVariant {
     Variant {
         ident: syn::Ident,
+        discriminant: Option<TokenStream>,
         options: Box<Struct>,
     },
     Unit(UnitEnumField),
@@ -336,10 +389,40 @@ impl EnumVariant {
         }
     }
 
+    pub(crate) fn discriminant(&self) -> Option<&TokenStream> {
+        match self {
+            Self::Variant { discriminant, .. } => discriminant.as_ref(),
+            Self::Unit(field) => field.discriminant.as_ref(),
+        }
+    }
+
     pub(crate) fn has_no_attrs(&self) -> bool {
         match self {
             Self::Variant { options, .. } => options.has_no_attrs(),
-            Self::Unit(_) => true,
+            Self::Unit(field) => {
+                field.tag_value.is_none() && field.other.is_none() && field.pattern.is_none()
+            }
+        }
+    }
+
+    pub(crate) fn is_other(&self) -> bool {
+        match self {
+            Self::Variant { options, .. } => options.other.is_some(),
+            Self::Unit(field) => field.other.is_some(),
+        }
+    }
+
+    pub(crate) fn magic(&self) -> &Magic {
+        match self {
+            Self::Variant { options, .. } => &options.magic,
+            Self::Unit(field) => &field.magic,
+        }
+    }
+
+    pub(crate) fn tag_value(&self) -> Option<&TokenStream> {
+        match self {
+            Self::Variant { options, .. } => options.tag_value.as_ref(),
+            Self::Unit(field) => field.tag_value.as_ref(),
         }
     }
 }
@@ -373,6 +456,10 @@ impl FromField for EnumVariant {
             }
             .map(|options| Self::Variant {
                 ident: variant.ident.clone(),
+                discriminant: variant
+                    .discriminant
+                    .as_ref()
+                    .map(|(_, expr)| expr.to_token_stream()),
                 options: Box::new(options),
             }),
             syn::Fields::Unit => UnitEnumField::from_field(variant, index, options).map(Self::Unit),
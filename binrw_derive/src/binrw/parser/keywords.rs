@@ -12,6 +12,7 @@ define_keywords! {
     args,
     args_raw,
     assert,
+    base,
     big,
     binread,
     br,
@@ -22,6 +23,7 @@ define_keywords! {
     count,
     dbg,
     default,
+    endian_marker,
     err_context,
     ignore,
     import,
@@ -30,20 +32,29 @@ define_keywords! {
     is_little,
     little,
     magic,
+    magic_range,
     map,
     map_stream,
     offset,
+    other,
     pad_after,
     pad_before,
     pad_size_to,
     parse_with,
+    pattern,
     pre_assert,
+    relative,
     repr,
     restore_position,
     return_all_errors,
     return_unexpected_error,
     seek_before,
     stream,
+    tag,
+    tag_type,
+    tag_value,
+    take,
+    take_exact,
     temp,
     try_calc,
     try_map,
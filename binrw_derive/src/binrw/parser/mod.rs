@@ -202,6 +202,25 @@ mod tests {
         }
     });
 
+    try_error!(conflicting_keyword_endian_marker: "conflicting endianness keyword" {
+        #[br(big, endian_marker(big = b"MM", little = b"II"))]
+        struct Foo {
+            a: i32,
+        }
+    });
+
+    try_error!(endian_marker_missing: "expected both `big` and `little`" {
+        #[br(endian_marker(big = b"MM"))]
+        struct Foo {
+            a: i32,
+        }
+    });
+
+    try_error!(conflicting_keyword_base: "conflicting `base` keyword" {
+        #[br(relative, base = 0)]
+        struct Foo;
+    });
+
     try_error!(conflicting_keyword_enum_error_mode: "conflicting error handling keyword" {
         #[br(return_all_errors, return_unexpected_error)]
         enum Foo {
@@ -344,6 +363,46 @@ mod tests {
         struct Foo;
     });
 
+    try_error!(tag_missing_tag_value: "requires a `tag_value`" {
+        #[br(import(kind: u8), tag = kind)]
+        enum Foo {
+            #[br(tag_value = 0)] A(u8),
+            B(u16),
+        }
+    });
+
+    try_error!(tag_value_missing_tag: "requires a `tag` directive" {
+        enum Foo {
+            #[br(tag_value = 0)] A(u8),
+        }
+    });
+
+    try_error!(tag_value_struct: "can only be used on enum variants" {
+        #[br(tag_value = 0)]
+        struct Foo;
+    });
+
+    try_error!(tag_value_unit_enum: "can only be used in an enum with a `tag` directive" {
+        #[br(repr = u8)]
+        enum Foo {
+            #[br(tag_value = 0)] A,
+        }
+    });
+
+    try_error!(take_calc_conflict: "`take` is incompatible" {
+        struct Foo {
+            #[br(calc = 1, take = 2)]
+            a: u8,
+        }
+    });
+
+    try_error!(take_map_stream_conflict: "`take` and `map_stream` are mutually exclusive" {
+        struct Foo {
+            #[br(take = 2, map_stream = |r| r)]
+            a: u8,
+        }
+    });
+
     try_error!(try_calc_conflict: "`try` is incompatible" {
         struct Foo {
             #[br(try, calc(None))]
@@ -377,6 +436,71 @@ mod tests {
         assert_eq!(error.into_iter().count(), 3);
     }
 
+    try_error!(other_data_enum_unit_variant: "requires a variant with a field to hold the tag" {
+        enum Foo {
+            A(u8),
+            #[br(other)] B,
+        }
+    });
+
+    try_error!(other_duplicate: "only one variant can be marked `other`" {
+        #[br(repr = u8)]
+        enum Foo {
+            #[br(other)] A(u8),
+            #[br(other)] B(u8),
+        }
+    });
+
+    try_error!(other_missing_repr: "`other` requires `repr`" {
+        enum Foo {
+            #[br(magic = 0u8)] A,
+            #[br(other)] B(u8),
+        }
+    });
+
+    try_error!(other_struct: "`other` can only be used on enum variants" {
+        #[br(other)]
+        struct Foo {
+            a: u8,
+        }
+    });
+
+    try_error!(other_unit_variant: "requires a variant with a single unnamed field" {
+        #[br(repr = u8)]
+        enum Foo {
+            A,
+            #[br(other)] B,
+        }
+    });
+
+    try_error!(pattern_data_enum: "`pattern` can only be used in a unit-like enum" {
+        enum Foo {
+            A(u8),
+            #[br(pattern = 1..=2)] B,
+        }
+    });
+
+    try_error!(pattern_missing_repr: "`pattern` requires `repr`" {
+        enum Foo {
+            #[br(magic = 0u8)] A,
+            #[br(pattern = 1..=2)] B(u8),
+        }
+    });
+
+    try_error!(pattern_other_conflict: "mutually exclusive" {
+        #[br(repr = u8)]
+        enum Foo {
+            #[br(other, pattern = 1..=2)] A(u8),
+        }
+    });
+
+    try_error!(pattern_unit_variant: "requires a variant with a single unnamed field" {
+        #[br(repr = u8)]
+        enum Foo {
+            #[br(magic_range = 1..=2)] A,
+        }
+    });
+
     try_error!(repr_magic_conflict: "mutually exclusive" {
         #[br(repr = u8)]
         enum Foo {
@@ -384,6 +508,14 @@ mod tests {
         }
     });
 
+    try_error!(repr_tag_conflict: "`repr` and `tag` are mutually exclusive" {
+        #[br(import(kind: u8), repr = u8, tag = kind)]
+        #[repr(u8)]
+        enum Foo {
+            #[br(tag_value = 0)] A(u8) = 0,
+        }
+    });
+
     try_error!(unsupported_type_enum: "null enums are not supported" {
         enum Foo {}
     });
@@ -1,11 +1,12 @@
 use super::{
     attr_struct,
-    types::{Assert, CondEndian, EnumErrorMode, Imports, Magic, Map},
+    types::{Assert, Base, CondEndian, EnumErrorMode, Imports, Magic, Map},
     EnumVariant, FromInput, ParseResult, StructField, TrySet, UnitEnumField,
 };
-use crate::binrw::Options;
-use proc_macro2::TokenStream;
-use quote::ToTokens;
+use crate::binrw::{is_binread_attr, is_binwrite_attr, Options};
+use proc_macro2::{TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use std::collections::HashSet;
 use syn::{spanned::Spanned, Ident};
 
 /// The parsed representation of binrw attributes on a data structure.
@@ -41,6 +42,8 @@ impl Input {
                     )
                 };
 
+                let read_struct = reject_variant_directives(read_struct, &input.ident);
+
                 if matches!(st.fields, syn::Fields::Unit) {
                     read_struct.map(Self::UnitStruct)
                 } else {
@@ -54,10 +57,7 @@ impl Input {
                         input.span(),
                         "null enums are not supported",
                     ))
-                } else if variants
-                    .iter()
-                    .all(|v| matches!(v.fields, syn::Fields::Unit))
-                {
+                } else if is_unit_only_enum(&input.attrs, variants.iter()) {
                     if options.write {
                         <UnitOnlyEnum as FromInput<UnitEnumAttr<true>>>::from_input(
                             attrs,
@@ -98,6 +98,13 @@ impl Input {
         }
     }
 
+    pub(crate) fn base(&self) -> Option<&Base> {
+        match self {
+            Input::Struct(s) | Input::UnitStruct(s) => s.base.as_ref(),
+            Input::Enum(_) | Input::UnitOnlyEnum(_) => None,
+        }
+    }
+
     pub(crate) fn endian(&self) -> &CondEndian {
         match self {
             Input::Struct(s) | Input::UnitStruct(s) => &s.endian,
@@ -197,13 +204,45 @@ impl Input {
     }
 }
 
+/// Returns an error if a `tag_value` or `other` directive was used on a struct,
+/// since they are only meaningful on enum variants.
+fn reject_variant_directives(result: ParseResult<Struct>, ident: &Ident) -> ParseResult<Struct> {
+    let (st, mut error) = match result {
+        ParseResult::Ok(st) => (st, None),
+        ParseResult::Partial(st, error) => (st, Some(error)),
+        ParseResult::Err(error) => return ParseResult::Err(error),
+    };
+
+    if let Some(tag_value) = &st.tag_value {
+        crate::combine_error(
+            &mut error,
+            syn::Error::new(
+                tag_value.span(),
+                "`tag_value` can only be used on enum variants",
+            ),
+        );
+    }
+
+    if st.other.is_some() {
+        crate::combine_error(
+            &mut error,
+            syn::Error::new(ident.span(), "`other` can only be used on enum variants"),
+        );
+    }
+
+    match error {
+        Some(error) => ParseResult::Partial(st, error),
+        None => ParseResult::Ok(st),
+    }
+}
+
 attr_struct! {
     #[from(StructAttr)]
     #[derive(Clone, Debug, Default)]
     pub(crate) struct Struct {
         #[from(RW:Stream)]
         pub(crate) stream_ident: Option<Ident>,
-        #[from(RW:Big, RW:Little, RW:IsBig, RW:IsLittle)]
+        #[from(RW:Big, RW:Little, RW:IsBig, RW:IsLittle, RW:EndianMarker)]
         pub(crate) endian: CondEndian,
         #[from(RW:Map, RW:TryMap, RW:Repr)]
         pub(crate) map: Map,
@@ -217,6 +256,12 @@ attr_struct! {
         pub(crate) assertions: Vec<Assert>,
         #[from(RO:PreAssert)]
         pub(crate) pre_assertions: Vec<Assert>,
+        #[from(RW:TagValue)]
+        pub(crate) tag_value: Option<TokenStream>,
+        #[from(RW:Other)]
+        pub(crate) other: Option<()>,
+        #[from(RW:Base, RW:Relative)]
+        pub(crate) base: Option<Base>,
         pub(crate) fields: Vec<StructField>,
         pub(crate) for_write: bool,
     }
@@ -244,6 +289,9 @@ impl Struct {
             && matches!(self.map, Map::None)
             && self.magic.is_none()
             && matches!(self.imports, Imports::None)
+            && self.tag_value.is_none()
+            && self.other.is_none()
+            && self.base.is_none()
             && self.fields.iter().all(StructField::has_no_attrs)
     }
 
@@ -251,11 +299,11 @@ impl Struct {
         let fields = self.iter_permanent_idents();
 
         if self.is_tuple() {
-            quote::quote! {
+            quote! {
                 (#(ref #fields),*)
             }
         } else {
-            quote::quote! {
+            quote! {
                 { #(ref #fields),* }
             }
         }
@@ -326,20 +374,116 @@ attr_struct! {
         pub(crate) pre_assertions: Vec<Assert>,
         #[from(RO:ReturnAllErrors, RO:ReturnUnexpectedError)]
         pub(crate) error_mode: EnumErrorMode,
+        #[from(RO:Tag)]
+        pub(crate) tag: Option<TokenStream>,
+        #[from(RW:TagType)]
+        pub(crate) tag_type: Option<TokenStream>,
         pub(crate) variants: Vec<EnumVariant>,
     }
 }
 
+impl Enum {
+    /// Returns the type of the discriminant if variants are selected by their
+    /// Rust discriminant values.
+    pub(crate) fn discriminant_repr(&self) -> Option<&TokenStream> {
+        self.map.as_repr().filter(|_| {
+            self.variants
+                .iter()
+                .any(|variant| variant.discriminant().is_some())
+        })
+    }
+
+    /// Returns the discriminant value of each variant, following the Rust
+    /// rules for implicit discriminants.
+    pub(crate) fn discriminants(&self) -> Vec<TokenStream> {
+        discriminants(self.variants.iter().map(EnumVariant::discriminant))
+    }
+
+    /// Returns the catch-all variant for unrecognised records, if one exists.
+    pub(crate) fn other_variant(&self) -> Option<&EnumVariant> {
+        self.variants.iter().find(|variant| variant.is_other())
+    }
+
+    /// Returns true if every variant starts with a distinct magic of the same
+    /// type, so that the variant can be selected by reading the magic once
+    /// before falling back to trying each variant in turn. Magics which cannot
+    /// be compared reliably, like floats, are always tried in turn.
+    pub(crate) fn is_magic_dispatch(&self) -> bool {
+        let mut kind = None;
+        let mut values = HashSet::new();
+
+        self.error_mode == EnumErrorMode::Default
+            && self.tag.is_none()
+            && self.variants.iter().all(|variant| {
+                let Some(magic) = variant.magic() else {
+                    return false;
+                };
+
+                let is_plain = match variant {
+                    EnumVariant::Variant { options, .. } => {
+                        matches!(options.endian, CondEndian::Inherited)
+                            && options.map_stream.is_none()
+                            && options.pre_assertions.is_empty()
+                    }
+                    EnumVariant::Unit(field) => field.pre_assertions.is_empty(),
+                };
+
+                is_plain
+                    && *kind.get_or_insert(magic.kind()) == magic.kind()
+                    && magic.value_key().map_or(false, |key| values.insert(key))
+            })
+    }
+}
+
 impl<const WRITE: bool> FromInput<EnumAttr<WRITE>> for Enum {
     type Field = EnumVariant;
 
     fn push_field(&mut self, field: Self::Field) -> syn::Result<()> {
+        if let EnumVariant::Unit(UnitEnumField {
+            pattern: Some(pattern),
+            ..
+        }) = &field
+        {
+            return Err(syn::Error::new(
+                pattern.span(),
+                "`pattern` can only be used in a unit-like enum",
+            ));
+        }
+
+        if field.is_other() {
+            let has_fields = match &field {
+                EnumVariant::Variant { options, .. } => !options.fields.is_empty(),
+                EnumVariant::Unit(_) => false,
+            };
+
+            if !has_fields {
+                return Err(syn::Error::new(
+                    field.ident().span(),
+                    "`other` on a data enum requires a variant with a field to hold the tag",
+                ));
+            } else if self.other_variant().is_some() {
+                return Err(syn::Error::new(
+                    field.ident().span(),
+                    "only one variant can be marked `other`",
+                ));
+            }
+        }
+
         self.variants.push(field);
         Ok(())
     }
 
-    fn validate(&self, _: Options) -> syn::Result<()> {
-        if self.map.is_some() {
+    fn validate(&self, options: Options) -> syn::Result<()> {
+        if let (Some(repr), Some(tag)) = (self.discriminant_repr(), &self.tag) {
+            let tag_span = tag.span();
+            let span = tag_span.join(repr.span()).unwrap_or(tag_span);
+            return Err(syn::Error::new(
+                span,
+                "`repr` and `tag` are mutually exclusive",
+            ));
+        }
+
+        if self.map.is_some() && self.discriminant_repr().is_none() {
             if let Some(variant) = self.variants.iter().find(|variant| !variant.has_no_attrs()) {
                 return Err(syn::Error::new(
                     variant.ident().span(),
@@ -347,6 +491,33 @@ impl<const WRITE: bool> FromInput<EnumAttr<WRITE>> for Enum {
                 ));
             }
         }
+
+        let tag_directive = if options.write {
+            self.tag_type.as_ref().map(|_| "tag_type")
+        } else {
+            self.tag.as_ref().map(|_| "tag")
+        };
+
+        if let Some(directive) = tag_directive {
+            if let Some(variant) = self
+                .variants
+                .iter()
+                .find(|variant| variant.tag_value().is_none() && !variant.is_other())
+            {
+                return Err(syn::Error::new(
+                    variant.ident().span(),
+                    format!("every variant of an enum with a `{directive}` directive requires a `tag_value`"),
+                ));
+            }
+        } else if !options.write {
+            if let Some(tag_value) = self.variants.iter().find_map(EnumVariant::tag_value) {
+                return Err(syn::Error::new(
+                    tag_value.span(),
+                    "`tag_value` requires a `tag` directive on the enum",
+                ));
+            }
+        }
+
         Ok(())
     }
 }
@@ -376,13 +547,56 @@ impl UnitOnlyEnum {
     pub(crate) fn is_magic_enum(&self) -> bool {
         self.is_magic_enum
     }
+
+    /// Returns the discriminant value of each variant, following the Rust
+    /// rules for implicit discriminants.
+    pub(crate) fn discriminants(&self) -> Vec<TokenStream> {
+        discriminants(self.fields.iter().map(|field| field.discriminant.as_ref()))
+    }
+
+    /// Returns the catch-all variant for unrecognised values, if one exists.
+    pub(crate) fn other_variant(&self) -> Option<&UnitEnumField> {
+        self.fields.iter().find(|field| field.other.is_some())
+    }
+
+    /// Returns true if any variant holds the raw discriminant value.
+    pub(crate) fn has_value_variants(&self) -> bool {
+        self.fields.iter().any(|field| field.has_value_field)
+    }
 }
 
 impl<const WRITE: bool> FromInput<UnitEnumAttr<WRITE>> for UnitOnlyEnum {
     type Field = UnitEnumField;
 
     fn push_field(&mut self, field: Self::Field) -> syn::Result<()> {
-        if let (Some(repr), Some(magic)) = (self.map.as_repr(), field.magic.as_ref()) {
+        if let Some(tag_value) = &field.tag_value {
+            Err(syn::Error::new(
+                tag_value.span(),
+                "`tag_value` can only be used in an enum with a `tag` directive",
+            ))
+        } else if let (Some(()), Some(pattern)) = (field.other, &field.pattern) {
+            Err(syn::Error::new(
+                pattern.span(),
+                "`other` and `pattern` are mutually exclusive",
+            ))
+        } else if (field.other.is_some() || field.pattern.is_some()) && !field.has_value_field {
+            Err(syn::Error::new(
+                field.ident.span(),
+                format!(
+                    "`{}` requires a variant with a single unnamed field to hold the value",
+                    if field.other.is_some() {
+                        "other"
+                    } else {
+                        "pattern"
+                    }
+                ),
+            ))
+        } else if field.other.is_some() && self.other_variant().is_some() {
+            Err(syn::Error::new(
+                field.ident.span(),
+                "only one variant can be marked `other`",
+            ))
+        } else if let (Some(repr), Some(magic)) = (self.map.as_repr(), field.magic.as_ref()) {
             let magic_span = magic.span();
             let span = magic_span.join(repr.span()).unwrap_or(magic_span);
             Err(syn::Error::new(
@@ -397,6 +611,22 @@ impl<const WRITE: bool> FromInput<UnitEnumAttr<WRITE>> for UnitOnlyEnum {
     }
 
     fn validate(&self, options: Options) -> syn::Result<()> {
+        if self.map.as_repr().is_none() {
+            if let Some(field) = self.fields.iter().find(|field| field.has_value_field) {
+                return Err(syn::Error::new(
+                    field.ident.span(),
+                    format!(
+                        "`{}` requires `repr` on the enum",
+                        if field.other.is_some() {
+                            "other"
+                        } else {
+                            "pattern"
+                        }
+                    ),
+                ));
+            }
+        }
+
         if self.map.as_repr().is_some() || self.is_magic_enum() {
             Ok(())
         } else if options.write {
@@ -406,3 +636,60 @@ impl<const WRITE: bool> FromInput<UnitEnumAttr<WRITE>> for UnitOnlyEnum {
         }
     }
 }
+
+/// Returns the discriminant value of each variant given the explicit
+/// discriminants, following the Rust rules for implicit discriminants.
+fn discriminants<'a>(explicit: impl Iterator<Item = Option<&'a TokenStream>>) -> Vec<TokenStream> {
+    let mut previous = None::<TokenStream>;
+    explicit
+        .map(|discriminant| {
+            let value = match (discriminant, previous.take()) {
+                (Some(discriminant), _) => quote! { (#discriminant) },
+                (None, Some(previous)) => quote! { (#previous + 1) },
+                (None, None) => quote! { 0 },
+            };
+            previous = Some(value.clone());
+            value
+        })
+        .collect()
+}
+
+/// Returns true if the enum should be parsed as a unit-like enum, which is the
+/// case if every variant is either a unit variant or a variant with a single
+/// unnamed field marked as holding the raw discriminant value, and the enum
+/// does not select variants by tag.
+fn is_unit_only_enum<'a>(
+    attrs: &[syn::Attribute],
+    mut variants: impl Iterator<Item = &'a syn::Variant>,
+) -> bool {
+    !has_directive(attrs, &["tag"])
+        && variants.all(|variant| match &variant.fields {
+            syn::Fields::Unit => true,
+            syn::Fields::Unnamed(fields) => {
+                fields.unnamed.len() == 1
+                    && has_directive(&variant.attrs, &["other", "pattern", "magic_range"])
+                    && !has_directive(&variant.attrs, &["tag_value"])
+            }
+            syn::Fields::Named(_) => false,
+        })
+}
+
+/// Returns true if any binrw attribute in `attrs` contains one of the given
+/// directives.
+fn has_directive(attrs: &[syn::Attribute], names: &[&str]) -> bool {
+    attrs
+        .iter()
+        .filter(|attr| is_binread_attr(attr) || is_binwrite_attr(attr))
+        .filter_map(|attr| attr.meta.require_list().ok())
+        .any(|list| {
+            list.tokens
+                .clone()
+                .into_iter()
+                .collect::<Vec<_>>()
+                .split(|token| matches!(token, TokenTree::Punct(punct) if punct.as_char() == ','))
+                .any(|directive| {
+                    matches!(directive.first(), Some(TokenTree::Ident(ident))
+                        if names.iter().any(|name| ident == name))
+                })
+        })
+}
@@ -0,0 +1,24 @@
+use crate::binrw::parser::attrs;
+use proc_macro2::TokenStream;
+
+/// The position that alignment and seek directives on the fields of a
+/// struct are relative to.
+#[derive(Debug, Clone)]
+pub(crate) enum Base {
+    /// Positions are relative to the start of the struct.
+    Relative,
+    /// Positions are relative to the given position.
+    Expr(TokenStream),
+}
+
+impl From<attrs::Base> for Base {
+    fn from(value: attrs::Base) -> Self {
+        Self::Expr(value.into())
+    }
+}
+
+impl From<attrs::Relative> for Base {
+    fn from(_: attrs::Relative) -> Self {
+        Self::Relative
+    }
+}
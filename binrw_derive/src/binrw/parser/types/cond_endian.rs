@@ -37,6 +37,10 @@ pub(crate) enum CondEndian {
     Inherited,
     Fixed(Endian),
     Cond(Endian, TokenStream),
+    Marker {
+        big: TokenStream,
+        little: TokenStream,
+    },
 }
 
 impl Default for CondEndian {
@@ -69,6 +73,15 @@ impl From<attrs::IsLittle> for CondEndian {
     }
 }
 
+impl From<attrs::EndianMarker> for CondEndian {
+    fn from(marker: attrs::EndianMarker) -> Self {
+        Self::Marker {
+            big: marker.value.big.into_token_stream(),
+            little: marker.value.little.into_token_stream(),
+        }
+    }
+}
+
 impl<T: Into<CondEndian> + KeywordToken> TrySet<CondEndian> for T {
     fn try_set(self, to: &mut CondEndian) -> syn::Result<()> {
         if matches!(*to, CondEndian::Inherited) {
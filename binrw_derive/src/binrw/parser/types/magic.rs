@@ -52,6 +52,21 @@ impl Inner {
         &self.1
     }
 
+    /// Returns the value of the magic in a form which can be compared with
+    /// other magics of the same kind, or `None` if the value cannot be compared
+    /// reliably.
+    pub(crate) fn value_key(&self) -> Option<Vec<u8>> {
+        match syn::parse2::<Lit>(self.1.clone()).ok()? {
+            Lit::ByteStr(bytes) => Some(bytes.value()),
+            Lit::Byte(byte) => Some(vec![byte.value()]),
+            Lit::Int(int) => int
+                .base10_parse::<u128>()
+                .ok()
+                .map(|value| value.to_le_bytes().to_vec()),
+            _ => None,
+        }
+    }
+
     #[cfg(feature = "verbose-backtrace")]
     pub(crate) fn into_match_value(self) -> TokenStream {
         self.1
@@ -1,4 +1,5 @@
 mod assert;
+mod base;
 mod cond_endian;
 mod condition;
 mod enum_error_mode;
@@ -9,8 +10,10 @@ mod magic;
 mod map;
 mod passed_args;
 mod spanned_value;
+mod take;
 
 pub(crate) use assert::{Assert, Error as AssertionError};
+pub(crate) use base::Base;
 pub(crate) use cond_endian::CondEndian;
 pub(crate) use condition::Condition;
 pub(crate) use enum_error_mode::EnumErrorMode;
@@ -21,6 +24,7 @@ pub(crate) use magic::Magic;
 pub(crate) use map::Map;
 pub(crate) use passed_args::PassedArgs;
 pub(crate) use spanned_value::SpannedValue;
+pub(crate) use take::Take;
 
 fn assert_all_args_consumed<Iter, IterItem>(
     args: Iter,
@@ -0,0 +1,29 @@
+use crate::binrw::parser::attrs;
+use proc_macro2::TokenStream;
+
+/// A limit on the number of bytes a field occupies in the stream.
+#[derive(Debug, Clone)]
+pub(crate) struct Take {
+    /// The number of bytes.
+    pub(crate) len: TokenStream,
+    /// Whether it is an error for the field to use fewer bytes than `len`.
+    pub(crate) exact: bool,
+}
+
+impl From<attrs::Take> for Take {
+    fn from(value: attrs::Take) -> Self {
+        Self {
+            len: value.into(),
+            exact: false,
+        }
+    }
+}
+
+impl From<attrs::TakeExact> for Take {
+    fn from(value: attrs::TakeExact) -> Self {
+        Self {
+            len: value.into(),
+            exact: true,
+        }
+    }
+}
@@ -49,6 +49,14 @@ pub(crate) type MetaType<Keyword> = MetaValue<Keyword, Type>;
 /// both are always allowed
 pub(crate) type MetaIdent<Keyword> = MetaValue<Keyword, Ident>;
 
+/// `MetaPat` represents a key/pattern pair
+/// Takes two forms:
+/// * ident(pat)
+/// * ident = pat
+///
+/// both are always allowed
+pub(crate) type MetaPat<Keyword> = MetaValue<Keyword, PatMulti>;
+
 /// `MetaLit` represents a key/lit pair
 /// Takes two forms:
 /// * ident(lit)
@@ -211,6 +219,23 @@ impl<Keyword: Token + Spanned, ParenItemType, BraceItemType> KeywordToken
     }
 }
 
+// This is like `syn::Pat` except it implements `Parse`, allowing a leading `|`
+// and top-level alternatives.
+#[derive(Debug, Clone)]
+pub(crate) struct PatMulti(syn::Pat);
+
+impl Parse for PatMulti {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        syn::Pat::parse_multi_with_leading_vert(input).map(Self)
+    }
+}
+
+impl ToTokens for PatMulti {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.0.to_tokens(tokens);
+    }
+}
+
 // This is like `syn::PatType` except:
 // (1) Implements `Parse`;
 // (2) No attributes;
@@ -258,6 +283,55 @@ impl Parse for IdentTypeMaybeDefault {
     }
 }
 
+// A pair of byte order markers, written as `big = $expr, little = $expr` in
+// either order.
+#[derive(Debug, Clone)]
+pub(crate) struct EndianMarkers {
+    pub(crate) big: Expr,
+    pub(crate) little: Expr,
+}
+
+impl Parse for EndianMarkers {
+    fn parse(input: ParseStream<'_>) -> syn::Result<Self> {
+        let span = input.span();
+        let mut big = None;
+        let mut little = None;
+        while !input.is_empty() {
+            let ident = input.parse::<Ident>()?;
+            let slot = if ident == "big" {
+                &mut big
+            } else if ident == "little" {
+                &mut little
+            } else {
+                return Err(syn::Error::new(ident.span(), "expected `big` or `little`"));
+            };
+            input.parse::<Token![=]>()?;
+            if slot.replace(input.parse::<Expr>()?).is_some() {
+                return Err(syn::Error::new(ident.span(), "duplicate endian marker"));
+            }
+            if !input.is_empty() {
+                input.parse::<Token![,]>()?;
+            }
+        }
+
+        match (big, little) {
+            (Some(big), Some(little)) => Ok(Self { big, little }),
+            _ => Err(syn::Error::new(
+                span,
+                "expected both `big` and `little` endian markers",
+            )),
+        }
+    }
+}
+
+impl ToTokens for EndianMarkers {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        let big = &self.big;
+        let little = &self.little;
+        tokens.extend(quote::quote! { big = #big, little = #little });
+    }
+}
+
 pub(crate) struct MetaAttrList<P>(Fields<P>);
 
 impl<P> MetaAttrList<P> {